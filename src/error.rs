@@ -0,0 +1,41 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum KoError {
+    InvalidParameter(String),
+    LengthMismatch(usize, usize),
+    NonNormalisedProbabilities(f64),
+    EmptySamples,
+    Io(std::io::Error),
+    Plotting(String),
+}
+
+impl fmt::Display for KoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KoError::InvalidParameter(msg) => write!(f, "invalid parameter: {}", msg),
+            KoError::LengthMismatch(x, y) => write!(f, "length mismatch: {} != {}", x, y),
+            KoError::NonNormalisedProbabilities(sum) => {
+                write!(f, "probabilities must sum to 1, but sum to {}", sum)
+            }
+            KoError::EmptySamples => write!(f, "at least one sample is required"),
+            KoError::Io(err) => write!(f, "io error: {}", err),
+            KoError::Plotting(msg) => write!(f, "plotting error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for KoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KoError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for KoError {
+    fn from(err: std::io::Error) -> Self {
+        KoError::Io(err)
+    }
+}
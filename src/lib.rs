@@ -1,3 +1,4 @@
+pub mod error;
 pub mod plotting;
 pub mod probability;
 pub mod tests;
\ No newline at end of file
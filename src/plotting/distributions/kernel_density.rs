@@ -0,0 +1,35 @@
+use crate::plotting::plot::plot_data;
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, NormalDistribution,
+};
+use crate::probability::kernel_density::{BandwidthSelection, Kernel, KernelDensityEstimate};
+use crate::probability::utils::sample::continuous_sample_with;
+use rand::{rngs::StdRng, SeedableRng};
+
+pub fn plot_kernel_density_pdf(seed: u64) -> Result<(), Box<dyn std::error::Error>> {
+    //! Plot pdf of a kernel density estimate of standard normal samples
+    //!
+    //! ## Arguments:
+    //! * `seed`: `u64`, seed of the random number generator used for sampling
+    //!
+    //! ## Returns:
+    //! * `Result<(), Box<dyn std::error::Error>>`: Result of plotting pdf of the kernel density estimate
+    let mut rng: StdRng = StdRng::seed_from_u64(seed);
+    let normal: NormalDistribution = NormalDistribution::new(0.0, 1.0);
+    let samples: Vec<f64> = continuous_sample_with(1000, &normal, &mut rng);
+    let kde: KernelDensityEstimate =
+        KernelDensityEstimate::try_new(&samples, Kernel::Gaussian, BandwidthSelection::Silverman)?;
+    let num_points: usize = 100000;
+    let min_x: f64 = -4.0;
+    let max_x: f64 = 4.0;
+    // collect data points
+    let data: Vec<(f64, f64)> = (0..num_points)
+        .map(|i| min_x + (i as f64 / num_points as f64) * (max_x - min_x))
+        .map(|x| (x, kde.pdf(x)))
+        .collect();
+    let caption: &str = "Kernel Density Estimate PDF";
+    let x_desc: &str = "x";
+    let y_desc: &str = "pdf(x)";
+    let save_file: &str = "plots/distributions/kernel_density/kernel_density_pdf.png";
+    plot_data(data, caption, x_desc, y_desc, save_file)
+}
@@ -1,2 +1,3 @@
+pub mod kernel_density;
 pub mod normal;
 pub mod power_law;
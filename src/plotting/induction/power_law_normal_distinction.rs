@@ -1,16 +1,20 @@
 use crate::plotting::plot::{plot_data, scatter_data};
 use crate::probability::continuous_distribution::{
-    ContinuousProbabilityDistribution, NormalDistribution, PowerLawDistribution,
+    ContinuousProbabilityDistribution, PowerLawDistribution,
+};
+use crate::probability::induction::model_selection::{
+    CandidateModel, ModelSelection, ModelSelectionResult,
 };
-use crate::probability::induction::continuous_testing::ks_distance;
-use crate::probability::induction::decision_entropy::compute_decision_entropy;
 use crate::probability::utils::empirical_moment::empirical_moment;
-use crate::probability::utils::sample::continuous_sample;
+use crate::probability::utils::sample::continuous_sample_with;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
 
 const PLOT_DIR: &str = "plots/induction/";
 
 fn collect_power_law_distinction_data(
     sample_dist: &impl ContinuousProbabilityDistribution,
+    rng: &mut dyn RngCore,
 ) -> (
     Vec<(f64, f64)>,
     Vec<(f64, f64)>,
@@ -25,27 +29,45 @@ fn collect_power_law_distinction_data(
     let mut decision_entropy_data: Vec<(f64, f64)> = Vec::new(); // (num_samples, decision_confidence)
     let mut stand_dev_entropy_data: Vec<(f64, f64)> = Vec::new(); // (variance, entropy)
 
+    // register candidate models
+    let normal: CandidateModel = CandidateModel::normal();
+    let power_law: CandidateModel = CandidateModel::power_law();
+    let normal_name: String = normal.name().to_string();
+    let power_law_name: String = power_law.name().to_string();
+    let model_selection: ModelSelection = ModelSelection::new()
+        .with_candidate(normal)
+        .with_candidate(power_law);
+
     // define number of samples
     let num_samples: usize = 200;
     let num_start_samples: usize = 20;
-    let mut samples: Vec<f64> = continuous_sample(num_start_samples, &*sample_dist);
+    let mut samples: Vec<f64> = continuous_sample_with(num_start_samples, &*sample_dist, rng);
 
     // iterate over samples
     for sample_idx in num_start_samples..num_samples {
         // sample from normal distribution
-        samples.push(sample_dist.sample());
-        // estimate normal distribution
-        let est_normal_dist: NormalDistribution = NormalDistribution::estimate(&samples);
-        // estimate power law distribution
-        let est_power_law_dist: PowerLawDistribution = PowerLawDistribution::estimate(&samples);
-        // calculate ks distances
-        let ks_distance_normal: f64 = ks_distance(&est_normal_dist, &samples);
-        let ks_distance_power_law: f64 = ks_distance(&est_power_law_dist, &samples);
+        samples.push(sample_dist.sample_with(rng));
+        // fit both candidates and decide between them, skipping sample sizes where one fails
+        let selection_result: ModelSelectionResult = match model_selection.try_select(&samples) {
+            Ok(selection_result) if selection_result.failures.is_empty() => selection_result,
+            _ => {
+                println!("Skipped sample {}: a candidate failed to fit", sample_idx);
+                continue;
+            }
+        };
+        let score_of = |name: &str| -> f64 {
+            selection_result
+                .fits
+                .iter()
+                .find(|fit| fit.name == name)
+                .map(|fit| fit.score)
+                .unwrap()
+        };
+        let ks_distance_normal: f64 = score_of(&normal_name);
+        let ks_distance_power_law: f64 = score_of(&power_law_name);
         // calculate standard deviation
         let stand_dev: f64 = empirical_moment(2, &samples).sqrt();
-        // calculate decision probabilities
-        let decision_entropy: f64 =
-            compute_decision_entropy(&vec![ks_distance_normal, ks_distance_power_law]).to_float();
+        let decision_entropy: f64 = selection_result.decision_entropy.to_float();
         // add data to vectors
         normal_ks_dist_data.push((sample_idx as f64, ks_distance_normal));
         power_law_ks_dist_data.push((sample_idx as f64, ks_distance_power_law));
@@ -64,33 +86,43 @@ fn collect_power_law_distinction_data(
 }
 
 pub fn average_data_collection(collection: &Vec<Vec<(f64, f64)>>) -> Vec<(f64, f64)> {
-    // average data over samples
-    let data_start: Vec<(f64, f64)> = collection[0].clone();
-    let data: Vec<(f64, f64)> = collection
-        .iter()
-        .fold(data_start.clone(), |acc, x| {
-            acc.iter()
-                .zip(x.iter())
-                .map(|(a, b)| (a.0 + b.0, a.1 + b.1))
-                .collect()
-        })
-        .iter()
-        .enumerate()
-        .map(|(idx, (x, y))| (x - data_start[idx].0, y - data_start[idx].1))
-        .map(|(x, y)| (x / collection.len() as f64, y / collection.len() as f64))
-        .collect();
-    data
+    // average points sharing an x value, as trials skip points where a candidate failed
+    let mut sums: Vec<(f64, f64, usize)> = Vec::new(); // (x, sum of y, count)
+    for &(x, y) in collection.iter().flatten() {
+        match sums.iter_mut().find(|(sum_x, _, _)| *sum_x == x) {
+            Some(sum) => {
+                sum.1 += y;
+                sum.2 += 1;
+            }
+            None => sums.push((x, y, 1)),
+        }
+    }
+    sums.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    sums.iter()
+        .map(|(x, sum_y, count)| (*x, sum_y / *count as f64))
+        .collect()
 }
 
 pub fn plot_normal_power_law_distinction() -> Result<(), Box<dyn std::error::Error>> {
-    //! Plot the distinction between a normal and power law distribution
+    //! Plot the distinction between a normal and power law distribution with a random seed
+    //!
+    //! ## Returns:
+    //! * `Result<(), Box<dyn std::error::Error>>`: Result of plotting data
+    plot_normal_power_law_distinction_with_seed(rand::thread_rng().gen())
+}
+
+pub fn plot_normal_power_law_distinction_with_seed(
+    seed: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    //! Plot the distinction between a normal and power law distribution. Runs with the same
+    //! `seed` produce identical plots.
     //!
     //! ## Arguments:
-    //! * `samples`: `&Vec<f64>`, samples to plot
-    //! * `save_file`: `&str`, file to save plot to
+    //! * `seed`: `u64`, seed of the random number generator used for sampling
     //!
     //! ## Returns:
     //! * `Result<(), Box<dyn std::error::Error>>`: Result of plotting data
+    let mut rng: StdRng = StdRng::seed_from_u64(seed);
 
     // create normal distribution to sample from
     let power_law_sample_dist: PowerLawDistribution = PowerLawDistribution::new(0., 2., 1.);
@@ -111,7 +143,7 @@ pub fn plot_normal_power_law_distinction() -> Result<(), Box<dyn std::error::Err
             variance_data,
             decision_entropy_data,
             stand_dev_entropy_data,
-        ) = collect_power_law_distinction_data(&power_law_sample_dist);
+        ) = collect_power_law_distinction_data(&power_law_sample_dist, &mut rng);
         // plot data
         plot_data(
             normal_ks_dist_data.clone(),
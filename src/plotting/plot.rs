@@ -1,3 +1,4 @@
+use crate::error::KoError;
 use plotters::prelude::*;
 
 pub fn plot_data(
@@ -63,9 +64,12 @@ pub fn plot_data(
     chart.draw_series(LineSeries::new(data, &RED))?;
 
     // present data
-    root.present().expect(
-        "Unable to write result to file, please make sure 'plots' dir exists under current dir",
-    );
+    root.present().map_err(|err| {
+        KoError::Plotting(format!(
+            "unable to write result to {}, please make sure its directory exists: {}",
+            save_file, err
+        ))
+    })?;
     println!("Result has been saved to {}", save_file);
     Ok(())
 }
@@ -133,9 +137,12 @@ pub fn scatter_data(
     chart.draw_series(data.iter().map(|point| Circle::new(*point, 3, &RED)))?;
 
     // present data
-    root.present().expect(
-        "Unable to write result to file, please make sure 'plots' dir exists under current dir",
-    );
+    root.present().map_err(|err| {
+        KoError::Plotting(format!(
+            "unable to write result to {}, please make sure its directory exists: {}",
+            save_file, err
+        ))
+    })?;
     println!("Result has been saved to {}", save_file);
     Ok(())
 }
@@ -1,7 +1,36 @@
-use rand::distributions::Distribution;
+use crate::error::KoError;
+use crate::probability::induction::power_law_fitting::try_fit_power_law_above;
+use crate::probability::utils::optimize::nelder_mead;
+use libm::{erf, erfc};
+use rand::distributions::{Distribution, Open01};
+use rand::{Rng, RngCore};
 use statrs::distribution::{Normal, Uniform};
+use statrs::function::erf::erfc_inv;
 
-const NUM_STEPS: usize = 10000; // for numerical integration
+const QUANTILE_MAX_ITERATIONS: usize = 200; // for bisection of the cdf
+
+pub(crate) fn binomial_coefficient(n: usize, k: usize) -> f64 {
+    //! Computes the binomial coefficient `n` choose `k`
+    (0..k).fold(1., |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+pub(crate) fn standard_normal_cdf(z: f64) -> f64 {
+    //! Computes the cdf of the standard normal distribution via the complementary error function
+    0.5 * erfc(-z / std::f64::consts::SQRT_2)
+}
+
+fn standard_normal_log_survival(z: f64) -> f64 {
+    //! Computes the logarithm of the survival function of the standard normal distribution,
+    //! switching to the asymptotic expansion of erfc once it underflows
+    let survival: f64 = standard_normal_cdf(-z);
+    if survival > 1e-300 {
+        return survival.ln();
+    }
+    let t: f64 = z / std::f64::consts::SQRT_2;
+    let t_2: f64 = t.powi(2);
+    let series: f64 = 1. - 1. / (2. * t_2) + 3. / (4. * t_2.powi(2)) - 15. / (8. * t_2.powi(3));
+    -t_2 - (t * std::f64::consts::PI.sqrt()).ln() + series.ln() - std::f64::consts::LN_2
+}
 
 pub trait ContinuousProbabilityDistribution {
     fn domain(&self) -> (f64, f64);
@@ -9,7 +38,190 @@ pub trait ContinuousProbabilityDistribution {
     fn pdf(&self, x: f64) -> f64;
     fn measure(&self, domain: &(f64, f64)) -> f64;
     fn cdf(&self, x: f64) -> f64;
-    fn sample(&self) -> f64;
+    fn raw_moment(&self, n: usize) -> Option<f64>;
+
+    fn log_pdf(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-pdf
+        //!
+        //! ## Returns:
+        //! * ln(pdf(`x`)): `f64`
+        self.pdf(x).ln()
+    }
+
+    fn log_likelihood(&self, samples: &[f64]) -> f64 {
+        //! Computes the log-likelihood of the distribution given `samples`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, observed samples
+        //!
+        //! ## Returns:
+        //! * sum of ln(pdf(`x`)) over `samples`: `f64`
+        samples.iter().map(|&x| self.log_pdf(x)).sum::<f64>()
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        //! Computes the survival function (complementary cdf) of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the survival function
+        //!
+        //! ## Returns:
+        //! * 1 - cdf(`x`): `f64`
+        1. - self.cdf(x)
+    }
+
+    fn log_survival(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the survival function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-survival function
+        //!
+        //! ## Returns:
+        //! * ln(1 - cdf(`x`)): `f64`
+        self.survival(x).ln()
+    }
+
+    fn hazard(&self, x: f64) -> f64 {
+        //! Computes the hazard rate of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the hazard rate
+        //!
+        //! ## Returns:
+        //! * pdf(`x`) / (1 - cdf(`x`)): `f64`
+        self.pdf(x) / self.survival(x)
+    }
+
+    fn mean(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, mean of the distribution, `None` if it diverges
+        self.raw_moment(1)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, variance of the distribution, `None` if it diverges
+        let mean: f64 = self.raw_moment(1)?;
+        Some(self.raw_moment(2)? - mean.powi(2))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, third standardized moment of the distribution, `None` if it diverges
+        let mean: f64 = self.raw_moment(1)?;
+        let second: f64 = self.raw_moment(2)?;
+        let third: f64 = self.raw_moment(3)?;
+        let variance: f64 = self.variance()?;
+        Some((third - 3. * mean * second + 2. * mean.powi(3)) / variance.powf(1.5))
+    }
+
+    fn kurtosis(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, fourth standardized moment of the distribution, `None` if it diverges
+        let mean: f64 = self.raw_moment(1)?;
+        let second: f64 = self.raw_moment(2)?;
+        let third: f64 = self.raw_moment(3)?;
+        let fourth: f64 = self.raw_moment(4)?;
+        let variance: f64 = self.variance()?;
+        Some(
+            (fourth - 4. * mean * third + 6. * mean.powi(2) * second - 3. * mean.powi(4))
+                / variance.powi(2),
+        )
+    }
+
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! Samples from the distribution by inverse transform sampling unless the implementor
+        //! provides a direct sampler.
+        //!
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, random number generator to draw from
+        //!
+        //! ## Returns:
+        //! * `sample`: `f64`
+        self.quantile(rng.sample(Open01))
+    }
+
+    fn sample(&self) -> f64 {
+        //! Samples from the distribution using the thread-local random number generator.
+        //!
+        //! ## Returns:
+        //! * `sample`: `f64`
+        self.sample_with(&mut rand::thread_rng())
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        //! Computes the `p`-quantile of the distribution, numerically inverting the cdf unless the
+        //! implementor provides a closed form.
+        //!
+        //! ## Arguments:
+        //! * `p`: `f64`, probability in `[0, 1]`
+        //!
+        //! ## Returns:
+        //! * quantile(`p`): `f64`
+        numerical_quantile(self, p)
+    }
+}
+
+pub fn numerical_quantile<D>(dist: &D, p: f64) -> f64
+where
+    D: ContinuousProbabilityDistribution + ?Sized,
+{
+    //! Computes the `p`-quantile of a distribution by bracketing and bisecting its cdf.
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&D`, a continuous probability distribution
+    //! * `p`: `f64`, probability in `[0, 1]`
+    //!
+    //! ## Returns:
+    //! * quantile(`p`): `f64`
+    assert!((0. ..=1.).contains(&p), "p must lie in [0, 1]");
+    let (mut lower, mut upper) = dist.domain();
+    if p == 0. {
+        return lower;
+    }
+    if p == 1. {
+        return upper;
+    }
+    // expand unbounded ends of the domain until the quantile is bracketed
+    let anchor: f64 = if lower.is_finite() {
+        lower
+    } else if upper.is_finite() {
+        upper
+    } else {
+        0.
+    };
+    let mut width: f64 = 1.;
+    if lower.is_infinite() {
+        lower = anchor - width;
+        while dist.cdf(lower) > p {
+            width *= 2.;
+            lower = anchor - width;
+        }
+    }
+    width = 1.;
+    if upper.is_infinite() {
+        upper = anchor + width;
+        while dist.cdf(upper) < p {
+            width *= 2.;
+            upper = anchor + width;
+        }
+    }
+    // bisect until the bracket cannot be narrowed any further
+    for _ in 0..QUANTILE_MAX_ITERATIONS {
+        let mid: f64 = 0.5 * (lower + upper);
+        if mid <= lower || mid >= upper {
+            break;
+        }
+        if dist.cdf(mid) < p {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+    }
+    0.5 * (lower + upper)
 }
 
 #[derive(Debug, Clone)]
@@ -29,22 +241,58 @@ impl NormalDistribution {
         //!
         //! ## Panics:
         //! * if `variance` is not positive
-        assert!(variance > 0., "variance must be positive");
-        Self { mean, variance }
+        Self::try_new(mean, variance).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(mean: f64, variance: f64) -> Result<Self, KoError> {
+        //! ## Arguments:
+        //! * `mean`: `f64`, mean of the distribution
+        //! * `variance`: `f64`, variance of the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<NormalDistribution, KoError>`: a new normal distribution with the given `mean`
+        //!   and `variance`, or `KoError::InvalidParameter` if `variance` is not positive
+        if !mean.is_finite() {
+            return Err(KoError::InvalidParameter("mean must be finite".to_string()));
+        }
+        if !(variance > 0. && variance.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "variance must be positive".to_string(),
+            ));
+        }
+        Ok(Self { mean, variance })
     }
 
-    pub fn estimate(samples: &Vec<f64>) -> Self {
+    pub fn estimate(samples: &[f64]) -> Self {
         //! Estimates the parameters of a normal distribution from samples.
         //!
         //! ## Arguments:
-        //! * `samples`: `&Vec<f64>`, samples from which to estimate the distribution
+        //! * `samples`: `&[f64]`, samples from which to estimate the distribution
         //!
         //! ## Returns:
         //! * `NormalDistribution`: a new normal distribution with the estimated parameters
+        //!
+        //! ## Panics:
+        //! * if `samples` is empty or the estimated variance is not positive
+        Self::try_estimate(samples).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_estimate(samples: &[f64]) -> Result<Self, KoError> {
+        //! Estimates the parameters of a normal distribution from samples.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from which to estimate the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<NormalDistribution, KoError>`: a new normal distribution with the estimated
+        //!   parameters
+        if samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
         let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
         let variance: f64 =
             samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
-        Self::new(mean, variance)
+        Self::try_new(mean, variance)
     }
 }
 
@@ -76,6 +324,18 @@ impl ContinuousProbabilityDistribution for NormalDistribution {
         coefficient * exponent.exp()
     }
 
+    fn log_pdf(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-pdf
+        //!
+        //! ## Returns:
+        //! * ln(pdf(`x`)): `f64`
+        -0.5 * (2. * std::f64::consts::PI * self.variance).ln()
+            - (x - self.mean).powi(2) / (2. * self.variance)
+    }
+
     fn measure(&self, domain: &(f64, f64)) -> f64 {
         //! Computes the measure of the pdf over the interval `domain`.
         //!
@@ -85,24 +345,17 @@ impl ContinuousProbabilityDistribution for NormalDistribution {
         //! ## Returns:
         //! * measure of the pdf over `domain`: `f64`
         assert!(domain.0 < domain.1);
-
-        // define transformed domain
-        let domain_length: f64 = domain.1 - domain.0;
-        let g_a: f64 = (domain.0 - self.mean) / domain_length;
-        let g_b: f64 = (domain.1 - self.mean) / domain_length;
-
-        // measure transformed function over interval
-        let mut measure: f64 = 0.0;
-        let delta: f64 = (g_b - g_a) / NUM_STEPS as f64;
-        let mut x: f64 = g_a;
-        for _ in 0..NUM_STEPS {
-            measure += delta * (-0.5 * domain_length.powi(2) / self.variance * x.powi(2)).exp();
-            x += delta;
+        let scale: f64 = (2. * self.variance).sqrt();
+        let z_a: f64 = (domain.0 - self.mean) / scale;
+        let z_b: f64 = (domain.1 - self.mean) / scale;
+        // pick the representation which avoids cancellation on either side of the mean
+        if z_a >= 0. {
+            0.5 * (erfc(z_a) - erfc(z_b))
+        } else if z_b <= 0. {
+            0.5 * (erfc(-z_b) - erfc(-z_a))
+        } else {
+            0.5 * (erf(z_b) - erf(z_a))
         }
-        // multiply by domain length and divide by sqrt(2pi*variance)
-        measure *= domain_length / (2. * std::f64::consts::PI * self.variance).sqrt();
-        // return measure
-        measure
     }
 
     fn cdf(&self, x: f64) -> f64 {
@@ -113,16 +366,111 @@ impl ContinuousProbabilityDistribution for NormalDistribution {
         //!
         //! ## Returns:
         //! * cdf(`x`): `f64`
-        self.measure(&(self.mean - 5. * self.variance.sqrt(), x))
+        standard_normal_cdf((x - self.mean) / self.variance.sqrt())
     }
 
-    fn sample(&self) -> f64 {
-        //! Samples from the distribution.
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! Samples from the distribution using the random number generator `rng`.
+        //!
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, source of randomness
         //!
         //! ## Returns:
         //! * `sample`: `f64`
         let normal: Normal = Normal::new(self.mean, self.variance.sqrt()).unwrap();
-        normal.sample(&mut rand::thread_rng())
+        normal.sample(rng)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        //! Computes the `p`-quantile of the distribution.
+        //!
+        //! ## Arguments:
+        //! * `p`: `f64`, probability in `[0, 1]`
+        //!
+        //! ## Returns:
+        //! * quantile(`p`): `f64`
+        assert!((0. ..=1.).contains(&p), "p must lie in [0, 1]");
+        self.mean - (2. * self.variance).sqrt() * erfc_inv(2. * p)
+    }
+
+    fn raw_moment(&self, n: usize) -> Option<f64> {
+        //! Computes the `n`th raw moment of the distribution.
+        //!
+        //! ## Arguments:
+        //! * `n`: `usize`, order of the moment
+        //!
+        //! ## Returns:
+        //! * `Option<f64>`, the `n`th raw moment, `None` if it diverges
+        // E[(mean + X)^n] with the central moments E[X^k] = (k - 1)!! variance^(k / 2) for even k
+        let mut double_factorial: f64 = 1.;
+        let mut moment: f64 = 0.;
+        for k in (0..=n).step_by(2) {
+            if k > 0 {
+                double_factorial *= (k - 1) as f64;
+            }
+            moment += binomial_coefficient(n, k)
+                * self.mean.powi((n - k) as i32)
+                * double_factorial
+                * self.variance.powi((k / 2) as i32);
+        }
+        Some(moment)
+    }
+
+    fn mean(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, mean of the distribution
+        Some(self.mean)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, variance of the distribution
+        Some(self.variance)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, third standardized moment of the distribution
+        Some(0.)
+    }
+
+    fn kurtosis(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, fourth standardized moment of the distribution
+        Some(3.)
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        //! Computes the survival function (complementary cdf) of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the survival function
+        //!
+        //! ## Returns:
+        //! * 1 - cdf(`x`): `f64`
+        standard_normal_cdf(-(x - self.mean) / self.variance.sqrt())
+    }
+
+    fn log_survival(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the survival function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-survival function
+        //!
+        //! ## Returns:
+        //! * ln(1 - cdf(`x`)): `f64`
+        standard_normal_log_survival((x - self.mean) / self.variance.sqrt())
+    }
+
+    fn hazard(&self, x: f64) -> f64 {
+        //! Computes the hazard rate of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the hazard rate
+        //!
+        //! ## Returns:
+        //! * pdf(`x`) / (1 - cdf(`x`)): `f64`
+        (self.log_pdf(x) - self.log_survival(x)).exp()
     }
 }
 
@@ -143,35 +491,96 @@ impl PowerLawDistribution {
         //!
         //! ## Returns:
         //! * `PowerLawDistribution` with the given parameters
-        assert!(exponent > 1., "exponent must be bigger than 1.");
-        assert!(shift < min_x, "min_x must be bigger than shift");
+        //!
+        //! ## Panics:
+        //! * if `exponent` is not bigger than 1 or `min_x` is not bigger than `shift`
+        Self::try_new(shift, exponent, min_x).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(shift: f64, exponent: f64, min_x: f64) -> Result<Self, KoError> {
+        //! ## Arguments:
+        //! * `shift`: `f64`, shift of the distribution
+        //! * `exponent`: `f64`, exponent of the distribution
+        //! * `min_x`: `f64`, minimum value of the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<PowerLawDistribution, KoError>` with the given parameters, or
+        //!   `KoError::InvalidParameter` if they do not describe a power law
+        if !(exponent > 1. && exponent.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "exponent must be bigger than 1.".to_string(),
+            ));
+        }
+        if !(shift < min_x && min_x.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "min_x must be bigger than shift".to_string(),
+            ));
+        }
         let factor: f64 = (exponent - 1.) / (min_x - shift).powf(1. - exponent);
-        Self {
+        Ok(Self {
             factor,
             shift,
             exponent,
             min_x,
-        }
+        })
     }
 
-    pub fn estimate(samples: &Vec<f64>) -> Self {
+    pub fn estimate(samples: &[f64]) -> Self {
         //! Estimates the parameters of the power law distribution from samples.
         //!
         //! ## Arguments:
-        //! * `samples`: `&Vec<f64>`, samples from the distribution
+        //! * `samples`: `&[f64]`, samples from the distribution
         //!
         //! ## Returns:
         //! * `PowerLawDistribution` with the estimated parameters
+        //!
+        //! ## Panics:
+        //! * if `samples` is empty, the smallest sample is not positive or no valid exponent can
+        //!   be estimated
+        Self::try_estimate(samples).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_estimate(samples: &[f64]) -> Result<Self, KoError> {
+        //! Estimates the parameters of the power law distribution from samples as an unshifted
+        //! power law supported from the smallest sample.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, positive samples from the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<PowerLawDistribution, KoError>` with the estimated parameters
+        if samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
+        if samples.iter().any(|x| x.is_nan()) {
+            return Err(KoError::InvalidParameter(
+                "samples must not be NaN".to_string(),
+            ));
+        }
         let min_x: f64 = samples
-            .clone()
             .iter()
+            .copied()
             .min_by(|x, y| x.partial_cmp(y).unwrap())
-            .unwrap()
-            .clone();
-        let shift: f64 = min_x - 1.;
-        let exponent: f64 =
-            1. + samples.len() as f64 / samples.iter().map(|x| (x / min_x).ln()).sum::<f64>();
-        Self::new(shift, exponent, min_x)
+            .unwrap();
+        try_fit_power_law_above(samples, min_x)
+    }
+
+    pub fn shift(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, shift of the distribution
+        self.shift
+    }
+
+    pub fn exponent(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, exponent of the distribution
+        self.exponent
+    }
+
+    pub fn min_x(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, minimum value of the distribution
+        self.min_x
     }
 }
 
@@ -196,7 +605,26 @@ impl ContinuousProbabilityDistribution for PowerLawDistribution {
         //!
         //! ## Returns:
         //! * pdf(`x`): `f64`
-        self.factor * x.powf(-self.exponent)
+        if x < self.min_x {
+            return 0.;
+        }
+        self.factor * (x - self.shift).powf(-self.exponent)
+    }
+
+    fn log_pdf(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-pdf
+        //!
+        //! ## Returns:
+        //! * ln(pdf(`x`)): `f64`
+        if x < self.min_x {
+            return f64::NEG_INFINITY;
+        }
+        // ln(factor) is expanded to avoid overflowing the power for large min_x
+        (self.exponent - 1.).ln() + (self.exponent - 1.) * (self.min_x - self.shift).ln()
+            - self.exponent * (x - self.shift).ln()
     }
 
     fn measure(&self, domain: &(f64, f64)) -> f64 {
@@ -208,7 +636,7 @@ impl ContinuousProbabilityDistribution for PowerLawDistribution {
         //! ## Returns:
         //! * measure of the pdf over `domain`: `f64`
         assert!(domain.0 < domain.1);
-        self.factor
+        self.factor / (self.exponent - 1.)
             * ((domain.0 - self.shift).powf(1. - self.exponent)
                 - (domain.1 - self.shift).powf(1. - self.exponent))
     }
@@ -220,18 +648,782 @@ impl ContinuousProbabilityDistribution for PowerLawDistribution {
         //! * `x`: `f64`, outcome at which to evaluate the cdf
         //!
         //! Returns:
-        //! * cdf(`x`): `f64`
+        //! * cdf(`x`): `f64`, 0 at and below min_x
+        if x <= self.min_x {
+            return 0.;
+        }
         self.measure(&(self.min_x, x))
     }
 
-    fn sample(&self) -> f64 {
-        //! Samples from the distribution.
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! Samples from the distribution using the random number generator `rng`.
+        //!
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, source of randomness
         //!
         //! ## Returns:
         //! * `sample`: `f64`
         let uniform: Uniform = Uniform::new(0., 1.).unwrap();
-        let uniform_sample: f64 = uniform.sample(&mut rand::thread_rng());
+        let uniform_sample: f64 = uniform.sample(rng);
         (self.min_x - self.shift) * (1. - uniform_sample).powf(1. / (1. - self.exponent))
             + self.shift
     }
+
+    fn quantile(&self, p: f64) -> f64 {
+        //! Computes the `p`-quantile of the distribution.
+        //!
+        //! ## Arguments:
+        //! * `p`: `f64`, probability in `[0, 1]`
+        //!
+        //! ## Returns:
+        //! * quantile(`p`): `f64`
+        assert!((0. ..=1.).contains(&p), "p must lie in [0, 1]");
+        (self.min_x - self.shift) * (1. - p).powf(1. / (1. - self.exponent)) + self.shift
+    }
+
+    fn raw_moment(&self, n: usize) -> Option<f64> {
+        //! Computes the `n`th raw moment of the distribution.
+        //!
+        //! ## Arguments:
+        //! * `n`: `usize`, order of the moment
+        //!
+        //! ## Returns:
+        //! * `Option<f64>`, the `n`th raw moment, `None` if it diverges
+        // the moments of order exponent - 1 and above diverge
+        if n as f64 >= self.exponent - 1. {
+            return None;
+        }
+        // E[(shift + scale * Y)^n] with the moments E[Y^k] of a power law with unit min_x
+        let scale: f64 = self.min_x - self.shift;
+        let moment: f64 = (0..=n)
+            .map(|k| {
+                binomial_coefficient(n, k)
+                    * self.shift.powi((n - k) as i32)
+                    * scale.powi(k as i32)
+                    * (self.exponent - 1.)
+                    / (self.exponent - 1. - k as f64)
+            })
+            .sum::<f64>();
+        Some(moment)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, variance of the distribution, `None` if it diverges
+        if self.exponent <= 3. {
+            return None;
+        }
+        let shape: f64 = self.exponent - 1.;
+        Some((self.min_x - self.shift).powi(2) * shape / ((shape - 1.).powi(2) * (shape - 2.)))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, third standardized moment of the distribution, `None` if it diverges
+        if self.exponent <= 4. {
+            return None;
+        }
+        let shape: f64 = self.exponent - 1.;
+        Some(2. * (1. + shape) / (shape - 3.) * ((shape - 2.) / shape).sqrt())
+    }
+
+    fn kurtosis(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, fourth standardized moment of the distribution, `None` if it diverges
+        if self.exponent <= 5. {
+            return None;
+        }
+        let shape: f64 = self.exponent - 1.;
+        let excess: f64 = 6. * (shape.powi(3) + shape.powi(2) - 6. * shape - 2.)
+            / (shape * (shape - 3.) * (shape - 4.));
+        Some(3. + excess)
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        //! Computes the survival function (complementary cdf) of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the survival function
+        //!
+        //! ## Returns:
+        //! * 1 - cdf(`x`): `f64`
+        self.log_survival(x).exp()
+    }
+
+    fn log_survival(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the survival function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-survival function
+        //!
+        //! ## Returns:
+        //! * ln(1 - cdf(`x`)): `f64`
+        if x <= self.min_x {
+            return 0.;
+        }
+        (1. - self.exponent) * ((x - self.shift) / (self.min_x - self.shift)).ln()
+    }
+
+    fn hazard(&self, x: f64) -> f64 {
+        //! Computes the hazard rate of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the hazard rate
+        //!
+        //! ## Returns:
+        //! * pdf(`x`) / (1 - cdf(`x`)): `f64`
+        if x < self.min_x {
+            return 0.;
+        }
+        (self.exponent - 1.) / (x - self.shift)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExponentialDistribution {
+    pub rate: f64,
+    pub location: f64,
+}
+
+impl ExponentialDistribution {
+    pub fn new(rate: f64, location: f64) -> Self {
+        //! ## Arguments:
+        //! * `rate`: `f64`, rate of the distribution
+        //! * `location`: `f64`, lower bound (x_min) of the distribution
+        //!
+        //! ## Returns:
+        //! * `ExponentialDistribution` with the given parameters
+        //!
+        //! ## Panics:
+        //! * if `rate` is not positive
+        Self::try_new(rate, location).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(rate: f64, location: f64) -> Result<Self, KoError> {
+        //! ## Arguments:
+        //! * `rate`: `f64`, rate of the distribution
+        //! * `location`: `f64`, lower bound (x_min) of the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<ExponentialDistribution, KoError>` with the given parameters, or
+        //!   `KoError::InvalidParameter` if `rate` is not positive
+        if !(rate > 0. && rate.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "rate must be positive".to_string(),
+            ));
+        }
+        if !location.is_finite() {
+            return Err(KoError::InvalidParameter(
+                "location must be finite".to_string(),
+            ));
+        }
+        Ok(Self { rate, location })
+    }
+
+    pub fn estimate(samples: &[f64]) -> Self {
+        //! Estimates the parameters of the exponential distribution from samples.
+        //! The location is estimated by the sample minimum.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from the distribution
+        //!
+        //! ## Returns:
+        //! * `ExponentialDistribution` with the estimated parameters
+        //!
+        //! ## Panics:
+        //! * if `samples` is empty or all samples coincide
+        Self::try_estimate(samples).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_estimate(samples: &[f64]) -> Result<Self, KoError> {
+        //! Estimates the parameters of the exponential distribution from samples.
+        //! The location is estimated by the sample minimum.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<ExponentialDistribution, KoError>` with the estimated parameters
+        if samples.iter().any(|x| x.is_nan()) {
+            return Err(KoError::InvalidParameter(
+                "samples must not be NaN".to_string(),
+            ));
+        }
+        let location: f64 = samples
+            .iter()
+            .copied()
+            .min_by(|x, y| x.partial_cmp(y).unwrap())
+            .ok_or(KoError::EmptySamples)?;
+        Self::try_estimate_with_location(samples, location)
+    }
+
+    pub fn estimate_with_location(samples: &[f64], location: f64) -> Self {
+        //! Estimates the rate of the exponential distribution from the samples at or above a fixed
+        //! `location`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from the distribution
+        //! * `location`: `f64`, lower bound (x_min) of the distribution
+        //!
+        //! ## Returns:
+        //! * `ExponentialDistribution` with the estimated rate
+        //!
+        //! ## Panics:
+        //! * if no sample lies above `location`
+        Self::try_estimate_with_location(samples, location).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_estimate_with_location(samples: &[f64], location: f64) -> Result<Self, KoError> {
+        //! Estimates the rate of the exponential distribution from the samples at or above a fixed
+        //! `location`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from the distribution
+        //! * `location`: `f64`, lower bound (x_min) of the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<ExponentialDistribution, KoError>` with the estimated rate
+        let tail: Vec<f64> = samples.iter().copied().filter(|&x| x >= location).collect();
+        if tail.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
+        let rate: f64 = tail.len() as f64 / tail.iter().map(|x| x - location).sum::<f64>();
+        Self::try_new(rate, location)
+    }
+}
+
+impl ContinuousProbabilityDistribution for ExponentialDistribution {
+    fn domain(&self) -> (f64, f64) {
+        //! ## Returns:
+        //! * `domain`: `(f64, f64)`, domain of the pdf
+        (self.location, f64::INFINITY)
+    }
+
+    fn range(&self) -> (f64, f64) {
+        //! ## Returns:
+        //! * `range`: `(f64, f64)`, range of the pdf
+        (0., self.rate)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        //! Computes the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the pdf
+        //!
+        //! ## Returns:
+        //! * pdf(`x`): `f64`
+        if x < self.location {
+            return 0.;
+        }
+        self.rate * (-self.rate * (x - self.location)).exp()
+    }
+
+    fn log_pdf(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-pdf
+        //!
+        //! ## Returns:
+        //! * ln(pdf(`x`)): `f64`
+        if x < self.location {
+            return f64::NEG_INFINITY;
+        }
+        self.rate.ln() - self.rate * (x - self.location)
+    }
+
+    fn measure(&self, domain: &(f64, f64)) -> f64 {
+        //! Computes the measure of the interval `domain`.
+        //!
+        //! ## Arguments:
+        //! * `domain`: `&(f64, f64)`, interval at which to evaluate the measure
+        //!
+        //! ## Returns:
+        //! * measure of the pdf over `domain`: `f64`
+        assert!(domain.0 < domain.1);
+        self.cdf(domain.1) - self.cdf(domain.0)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        //! Computes the cumulative density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the cdf
+        //!
+        //! ## Returns:
+        //! * cdf(`x`): `f64`
+        if x <= self.location {
+            return 0.;
+        }
+        -(-self.rate * (x - self.location)).exp_m1()
+    }
+
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! Samples from the distribution using the random number generator `rng`.
+        //!
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, source of randomness
+        //!
+        //! ## Returns:
+        //! * `sample`: `f64`
+        let uniform: Uniform = Uniform::new(0., 1.).unwrap();
+        let uniform_sample: f64 = uniform.sample(rng);
+        self.location - (-uniform_sample).ln_1p() / self.rate
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        //! Computes the `p`-quantile of the distribution.
+        //!
+        //! ## Arguments:
+        //! * `p`: `f64`, probability in `[0, 1]`
+        //!
+        //! ## Returns:
+        //! * quantile(`p`): `f64`
+        assert!((0. ..=1.).contains(&p), "p must lie in [0, 1]");
+        self.location - (-p).ln_1p() / self.rate
+    }
+
+    fn raw_moment(&self, n: usize) -> Option<f64> {
+        //! Computes the `n`th raw moment of the distribution.
+        //!
+        //! ## Arguments:
+        //! * `n`: `usize`, order of the moment
+        //!
+        //! ## Returns:
+        //! * `Option<f64>`, the `n`th raw moment, `None` if it diverges
+        // E[(location + Y)^n] with the moments E[Y^k] = k! / rate^k
+        let mut factorial: f64 = 1.;
+        let mut moment: f64 = 0.;
+        for k in 0..=n {
+            if k > 0 {
+                factorial *= k as f64;
+            }
+            moment += binomial_coefficient(n, k) * self.location.powi((n - k) as i32) * factorial
+                / self.rate.powi(k as i32);
+        }
+        Some(moment)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, variance of the distribution
+        Some(1. / self.rate.powi(2))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, third standardized moment of the distribution
+        Some(2.)
+    }
+
+    fn kurtosis(&self) -> Option<f64> {
+        //! ## Returns:
+        //! * `Option<f64>`, fourth standardized moment of the distribution
+        Some(9.)
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        //! Computes the survival function (complementary cdf) of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the survival function
+        //!
+        //! ## Returns:
+        //! * 1 - cdf(`x`): `f64`
+        self.log_survival(x).exp()
+    }
+
+    fn log_survival(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the survival function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-survival function
+        //!
+        //! ## Returns:
+        //! * ln(1 - cdf(`x`)): `f64`
+        if x <= self.location {
+            return 0.;
+        }
+        -self.rate * (x - self.location)
+    }
+
+    fn hazard(&self, x: f64) -> f64 {
+        //! Computes the hazard rate of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the hazard rate
+        //!
+        //! ## Returns:
+        //! * pdf(`x`) / (1 - cdf(`x`)): `f64`
+        if x < self.location {
+            return 0.;
+        }
+        self.rate
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogNormalDistribution {
+    mu: f64,
+    sigma: f64,
+    min_x: f64,
+    tail_mass: f64,
+}
+
+impl LogNormalDistribution {
+    pub fn new(mu: f64, sigma: f64) -> Self {
+        //! ## Arguments:
+        //! * `mu`: `f64`, mean of the logarithm of the distribution
+        //! * `sigma`: `f64`, standard deviation of the logarithm of the distribution
+        //!
+        //! ## Returns:
+        //! * `LogNormalDistribution` with the given parameters
+        //!
+        //! ## Panics:
+        //! * if `sigma` is not positive
+        Self::new_truncated(mu, sigma, 0.)
+    }
+
+    pub fn new_truncated(mu: f64, sigma: f64, min_x: f64) -> Self {
+        //! ## Arguments:
+        //! * `mu`: `f64`, mean of the logarithm of the untruncated distribution
+        //! * `sigma`: `f64`, standard deviation of the logarithm of the untruncated distribution
+        //! * `min_x`: `f64`, lower truncation point of the distribution
+        //!
+        //! ## Returns:
+        //! * `LogNormalDistribution` truncated to `[min_x, inf)`
+        //!
+        //! ## Panics:
+        //! * if `sigma` is not positive, if `min_x` is negative or if the truncated tail has no mass
+        Self::try_new_truncated(mu, sigma, min_x).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(mu: f64, sigma: f64) -> Result<Self, KoError> {
+        //! ## Arguments:
+        //! * `mu`: `f64`, mean of the logarithm of the distribution
+        //! * `sigma`: `f64`, standard deviation of the logarithm of the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<LogNormalDistribution, KoError>` with the given parameters
+        Self::try_new_truncated(mu, sigma, 0.)
+    }
+
+    pub fn try_new_truncated(mu: f64, sigma: f64, min_x: f64) -> Result<Self, KoError> {
+        //! ## Arguments:
+        //! * `mu`: `f64`, mean of the logarithm of the untruncated distribution
+        //! * `sigma`: `f64`, standard deviation of the logarithm of the untruncated distribution
+        //! * `min_x`: `f64`, lower truncation point of the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<LogNormalDistribution, KoError>` truncated to `[min_x, inf)`, or
+        //!   `KoError::InvalidParameter` if the parameters do not describe a log-normal tail
+        if !mu.is_finite() {
+            return Err(KoError::InvalidParameter("mu must be finite".to_string()));
+        }
+        if !(sigma > 0. && sigma.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "sigma must be positive".to_string(),
+            ));
+        }
+        if !(min_x >= 0. && min_x.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "min_x must be non-negative".to_string(),
+            ));
+        }
+        let tail_mass: f64 = standard_normal_cdf(-(min_x.ln() - mu) / sigma);
+        if tail_mass <= 0. {
+            return Err(KoError::InvalidParameter(
+                "min_x must lie inside the support".to_string(),
+            ));
+        }
+        Ok(Self {
+            mu,
+            sigma,
+            min_x,
+            tail_mass,
+        })
+    }
+
+    pub fn estimate(samples: &[f64]) -> Self {
+        //! Estimates the parameters of the log-normal distribution from samples.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, positive samples from the distribution
+        //!
+        //! ## Returns:
+        //! * `LogNormalDistribution` with the estimated parameters
+        //!
+        //! ## Panics:
+        //! * if `samples` is empty or contains non-positive values
+        Self::try_estimate(samples).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_estimate(samples: &[f64]) -> Result<Self, KoError> {
+        //! Estimates the parameters of the log-normal distribution from samples.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, positive samples from the distribution
+        //!
+        //! ## Returns:
+        //! * `Result<LogNormalDistribution, KoError>` with the estimated parameters
+        if samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
+        if samples.iter().any(|&x| x <= 0.) {
+            return Err(KoError::InvalidParameter(
+                "samples must be positive".to_string(),
+            ));
+        }
+        let log_samples: Vec<f64> = samples.iter().map(|x| x.ln()).collect();
+        let mu: f64 = log_samples.iter().sum::<f64>() / log_samples.len() as f64;
+        let sigma: f64 = (log_samples.iter().map(|y| (y - mu).powi(2)).sum::<f64>()
+            / log_samples.len() as f64)
+            .sqrt();
+        Self::try_new(mu, sigma)
+    }
+
+    pub fn estimate_truncated(samples: &[f64], min_x: f64) -> Self {
+        //! Estimates the parameters of a log-normal distribution truncated at `min_x` by maximising
+        //! the likelihood of the samples at or above `min_x`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from the distribution
+        //! * `min_x`: `f64`, lower truncation point
+        //!
+        //! ## Returns:
+        //! * `LogNormalDistribution` with the estimated parameters
+        //!
+        //! ## Panics:
+        //! * if no positive sample lies above `min_x`
+        Self::try_estimate_truncated(samples, min_x).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_estimate_truncated(samples: &[f64], min_x: f64) -> Result<Self, KoError> {
+        //! Estimates the parameters of a log-normal distribution truncated at `min_x` by maximising
+        //! the likelihood of the samples at or above `min_x`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from the distribution
+        //! * `min_x`: `f64`, lower truncation point
+        //!
+        //! ## Returns:
+        //! * `Result<LogNormalDistribution, KoError>` with the estimated parameters
+        let log_samples: Vec<f64> = samples
+            .iter()
+            .filter(|&&x| x >= min_x && x > 0.)
+            .map(|x| x.ln())
+            .collect();
+        if log_samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
+        let n: f64 = log_samples.len() as f64;
+        let log_min_x: f64 = min_x.ln();
+        // the untruncated estimate serves as a starting point
+        let start_mu: f64 = log_samples.iter().sum::<f64>() / n;
+        let start_sigma: f64 = (log_samples
+            .iter()
+            .map(|y| (y - start_mu).powi(2))
+            .sum::<f64>()
+            / n)
+            .sqrt();
+        // negative log-likelihood in (mu, ln(sigma)), dropping terms independent of the parameters
+        let negative_log_likelihood = |params: &[f64]| -> f64 {
+            let (mu, sigma) = (params[0], params[1].exp());
+            // the log tail mass stays finite where the tail mass itself underflows
+            let log_tail_mass: f64 = standard_normal_log_survival((log_min_x - mu) / sigma);
+            let squares: f64 = log_samples.iter().map(|y| (y - mu).powi(2)).sum::<f64>();
+            n * (sigma.ln() + log_tail_mass) + squares / (2. * sigma.powi(2))
+        };
+        let params: Vec<f64> = nelder_mead(
+            negative_log_likelihood,
+            &[start_mu, start_sigma.ln()],
+            0.1,
+            1e-12,
+            1000,
+        );
+        Self::try_new_truncated(params[0], params[1].exp(), min_x)
+    }
+
+    pub fn mu(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, mean of the logarithm of the untruncated distribution
+        self.mu
+    }
+
+    pub fn sigma(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, standard deviation of the logarithm of the untruncated distribution
+        self.sigma
+    }
+
+    pub fn min_x(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, lower truncation point
+        self.min_x
+    }
+}
+
+impl ContinuousProbabilityDistribution for LogNormalDistribution {
+    fn domain(&self) -> (f64, f64) {
+        //! ## Returns:
+        //! * `domain`: `(f64, f64)`, domain of the pdf
+        (self.min_x, f64::INFINITY)
+    }
+
+    fn range(&self) -> (f64, f64) {
+        //! ## Returns:
+        //! * `range`: `(f64, f64)`, range of the pdf
+        (0., f64::INFINITY)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        //! Computes the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the pdf
+        //!
+        //! ## Returns:
+        //! * pdf(`x`): `f64`
+        if x < self.min_x || x <= 0. {
+            return 0.;
+        }
+        let z: f64 = (x.ln() - self.mu) / self.sigma;
+        let coefficient: f64 =
+            1. / (x * self.sigma * (2. * std::f64::consts::PI).sqrt() * self.tail_mass);
+        coefficient * (-0.5 * z.powi(2)).exp()
+    }
+
+    fn log_pdf(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-pdf
+        //!
+        //! ## Returns:
+        //! * ln(pdf(`x`)): `f64`
+        if x < self.min_x || x <= 0. {
+            return f64::NEG_INFINITY;
+        }
+        let z: f64 = (x.ln() - self.mu) / self.sigma;
+        -x.ln()
+            - self.sigma.ln()
+            - 0.5 * (2. * std::f64::consts::PI).ln()
+            - self.tail_mass.ln()
+            - 0.5 * z.powi(2)
+    }
+
+    fn measure(&self, domain: &(f64, f64)) -> f64 {
+        //! Computes the measure of the interval `domain`.
+        //!
+        //! ## Arguments:
+        //! * `domain`: `&(f64, f64)`, interval at which to evaluate the measure
+        //!
+        //! ## Returns:
+        //! * measure of the pdf over `domain`: `f64`
+        assert!(domain.0 < domain.1);
+        self.cdf(domain.1) - self.cdf(domain.0)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        //! Computes the cumulative density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the cdf
+        //!
+        //! ## Returns:
+        //! * cdf(`x`): `f64`
+        if x <= self.min_x || x <= 0. {
+            return 0.;
+        }
+        let z: f64 = (x.ln() - self.mu) / self.sigma;
+        // evaluated through survival functions to stay accurate in the upper tail
+        1. - standard_normal_cdf(-z) / self.tail_mass
+    }
+
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! Samples from the distribution by inverse transform sampling using the random number
+        //! generator `rng`.
+        //!
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, source of randomness
+        //!
+        //! ## Returns:
+        //! * `sample`: `f64`
+        let uniform: Uniform = Uniform::new(0., 1.).unwrap();
+        let uniform_sample: f64 = uniform.sample(rng);
+        // invert the survival function of the standard normal restricted to the tail
+        let z: f64 = std::f64::consts::SQRT_2 * erfc_inv(2. * uniform_sample * self.tail_mass);
+        (self.mu + self.sigma * z).exp()
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        //! Computes the `p`-quantile of the distribution.
+        //!
+        //! ## Arguments:
+        //! * `p`: `f64`, probability in `[0, 1]`
+        //!
+        //! ## Returns:
+        //! * quantile(`p`): `f64`
+        assert!((0. ..=1.).contains(&p), "p must lie in [0, 1]");
+        let z: f64 = std::f64::consts::SQRT_2 * erfc_inv(2. * (1. - p) * self.tail_mass);
+        (self.mu + self.sigma * z).exp()
+    }
+
+    fn raw_moment(&self, n: usize) -> Option<f64> {
+        //! Computes the `n`th raw moment of the distribution.
+        //!
+        //! ## Arguments:
+        //! * `n`: `usize`, order of the moment
+        //!
+        //! ## Returns:
+        //! * `Option<f64>`, the `n`th raw moment, `None` if it diverges
+        // the truncated tail of exp(n * ln(x)) weights is a shifted normal tail
+        let n: f64 = n as f64;
+        let shifted_tail_mass: f64 =
+            standard_normal_cdf((self.mu + n * self.sigma.powi(2) - self.min_x.ln()) / self.sigma);
+        Some(
+            (n * self.mu + 0.5 * n.powi(2) * self.sigma.powi(2)).exp() * shifted_tail_mass
+                / self.tail_mass,
+        )
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        //! Computes the survival function (complementary cdf) of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the survival function
+        //!
+        //! ## Returns:
+        //! * 1 - cdf(`x`): `f64`
+        if x <= self.min_x || x <= 0. {
+            return 1.;
+        }
+        standard_normal_cdf(-(x.ln() - self.mu) / self.sigma) / self.tail_mass
+    }
+
+    fn log_survival(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the survival function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-survival function
+        //!
+        //! ## Returns:
+        //! * ln(1 - cdf(`x`)): `f64`
+        if x <= self.min_x || x <= 0. {
+            return 0.;
+        }
+        standard_normal_log_survival((x.ln() - self.mu) / self.sigma) - self.tail_mass.ln()
+    }
+
+    fn hazard(&self, x: f64) -> f64 {
+        //! Computes the hazard rate of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the hazard rate
+        //!
+        //! ## Returns:
+        //! * pdf(`x`) / (1 - cdf(`x`)): `f64`
+        (self.log_pdf(x) - self.log_survival(x)).exp()
+    }
 }
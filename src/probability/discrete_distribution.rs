@@ -1,4 +1,5 @@
-use rand::{rngs::ThreadRng, Rng};
+use crate::error::KoError;
+use rand::{Rng, RngCore};
 use std::hash::Hash;
 
 #[derive(Clone, Debug)]
@@ -25,23 +26,38 @@ impl<T> DiscreteProbabilityDistribution<T> {
         //! Panics if the lengths of `outcomes` and `prbobabilities` are not equal, if the
         //! probabilities are not non-negative, or if the probabilities do not
         //! sum to 1.
-        assert_eq!(
-            outcomes.len(),
-            probabilities.len(),
-            "outcomes and probabilities must have the same length"
-        );
-        assert!(
-            probabilities.iter().all(|&p| p >= -1e-10),
-            "probabilities must be non-negative"
-        );
-        assert!(
-            (probabilities.iter().sum::<f64>() - 1.).abs() < 1e-10,
-            "probabilities must sum to 1"
-        );
-        Self {
+        Self::try_new(outcomes, probabilities).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(outcomes: Vec<T>, probabilities: Vec<f64>) -> Result<Self, KoError> {
+        //! Creates a new `DiscreteProbabilityDistribution` from a vector of
+        //! outcomes and a vector of probabilities.
+        //!
+        //! ## Arguments:
+        //! * `outcomes`: `Vec<T>`, vector of outcomes
+        //! * `probabilities`: `Vec<f64>`, vector of probabilities
+        //!
+        //! ## Returns:
+        //! * `Result<DiscreteProbabilityDistribution<T>, KoError>`, the discrete probability
+        //!   distribution, or an error if the lengths of `outcomes` and `probabilities` are not
+        //!   equal, if the probabilities are not non-negative, or if the probabilities do not
+        //!   sum to 1
+        if outcomes.len() != probabilities.len() {
+            return Err(KoError::LengthMismatch(outcomes.len(), probabilities.len()));
+        }
+        if !probabilities.iter().all(|&p| p >= -1e-10) {
+            return Err(KoError::InvalidParameter(
+                "probabilities must be non-negative".to_string(),
+            ));
+        }
+        let sum: f64 = probabilities.iter().sum::<f64>();
+        if (sum - 1.).abs() >= 1e-10 || sum.is_nan() {
+            return Err(KoError::NonNormalisedProbabilities(sum));
+        }
+        Ok(Self {
             outcomes,
             probabilities,
-        }
+        })
     }
 
     pub fn probabilities(&self) -> Vec<f64> {
@@ -64,7 +80,15 @@ where
     pub fn sample(&self) -> T {
         //! ## Returns:
         //! * `T`, a random outcome
-        let mut rng: ThreadRng = rand::thread_rng();
+        self.sample_with(&mut rand::thread_rng())
+    }
+
+    pub fn sample_with(&self, rng: &mut dyn RngCore) -> T {
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, random number generator to draw from
+        //!
+        //! ## Returns:
+        //! * `T`, a random outcome
         let mut u: f64 = rng.gen::<f64>();
         let mut i: usize = 0;
         while u > 0. {
@@ -82,6 +106,16 @@ where
         //! * `Vec<T>`, a vector of random outcomes
         (0..n).map(|_| self.sample()).collect()
     }
+
+    pub fn sample_n_with(&self, n: usize, rng: &mut dyn RngCore) -> Vec<T> {
+        //! ## Arguments:
+        //! * `n`: `usize`, number of samples
+        //! * `rng`: `&mut dyn RngCore`, random number generator to draw from
+        //!
+        //! ## Returns:
+        //! * `Vec<T>`, a vector of random outcomes
+        (0..n).map(|_| self.sample_with(rng)).collect()
+    }
 }
 
 impl<T> DiscreteProbabilityDistribution<T>
@@ -250,6 +284,25 @@ impl DiscreteProbabilityDistribution<i32> {
         //! ## Returns:
         //! * `DiscreteProbabilityDistribution<T>`, the discrete probability
         //! distribution
+        //!
+        //! ## Panics:
+        //! * if `samples` is empty
+        Self::try_estimate(samples).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_estimate(samples: &Vec<i32>) -> Result<Self, KoError> {
+        //! Estimates a `DiscreteProbabilityDistribution` from a vector of
+        //! samples.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&Vec<i32>`, a vector of samples
+        //!
+        //! ## Returns:
+        //! * `Result<DiscreteProbabilityDistribution<i32>, KoError>`, the discrete probability
+        //!   distribution, or `KoError::EmptySamples` if `samples` is empty
+        if samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
         let mut outcomes: Vec<i32> = samples.clone();
         outcomes.sort();
         outcomes.dedup();
@@ -257,7 +310,7 @@ impl DiscreteProbabilityDistribution<i32> {
             .iter()
             .map(|x| samples.iter().filter(|&y| y == x).count() as f64 / samples.len() as f64)
             .collect();
-        Self::new(outcomes, probabilities)
+        Self::try_new(outcomes, probabilities)
     }
 }
 
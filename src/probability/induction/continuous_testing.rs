@@ -1,20 +1,16 @@
+use crate::error::KoError;
 use crate::probability::continuous_distribution::ContinuousProbabilityDistribution;
+use crate::probability::induction::empirical_cdf::EmpiricalCdf;
+use std::f64::consts::PI;
 
-pub const NUM_STEPS: usize = 1000;
 const EPSILON: f64 = 0.05;
+pub const KS_EXACT_MAX_SAMPLES: usize = 100;
+const KOLMOGOROV_SERIES_TERMS: usize = 100;
 
-fn continuous_set_reduce(set: &Vec<f64>) -> Vec<f64> {
-    //! Reduces a Vec<f64> to a set of unique values
-    //!
-    //! ## Arguments:
-    //! * `set`: `&Vec<f64>`
-    //!
-    //! ## Returns:
-    //! * reduced_set: `Vec<f64>`, a vector of unique values
-    let mut reduced_set: Vec<f64> = set.clone();
-    reduced_set.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    reduced_set.dedup();
-    reduced_set.to_vec()
+#[derive(Debug, Clone, Copy)]
+pub struct KsTest {
+    pub statistic: f64,
+    pub p_value: f64,
 }
 
 pub fn ks_estimate_continuous_cdf(samples: &Vec<f64>) -> Vec<(f64, f64)> {
@@ -25,18 +21,7 @@ pub fn ks_estimate_continuous_cdf(samples: &Vec<f64>) -> Vec<(f64, f64)> {
     //!
     //! ## Returns:
     //! * cdf: `Vec<(f64, f64)>`, a vector of (x, y) pairs where x is an outcome and y is relative frequency of that outcome
-    let n: usize = samples.len();
-    let cdf_x: Vec<f64> = continuous_set_reduce(&samples);
-    let cdf_y: Vec<f64> = cdf_x
-        .clone()
-        .into_iter()
-        .map(|x| samples.iter().filter(|&y| *y <= x).count() as f64 / n as f64)
-        .collect::<Vec<f64>>();
-    cdf_x
-        .iter()
-        .zip(cdf_y.iter())
-        .map(|(&x, &y)| (x, y))
-        .collect()
+    EmpiricalCdf::new(samples).steps()
 }
 
 pub fn ks_evaluate_estimated_cdf(est_dist: &Vec<(f64, f64)>, x: &f64) -> f64 {
@@ -47,17 +32,41 @@ pub fn ks_evaluate_estimated_cdf(est_dist: &Vec<(f64, f64)>, x: &f64) -> f64 {
     //! * `x`: &f64, an outcome
     //!
     //! ## Returns:
-    //! * the estimated CDF at `x`: `f64`
-    est_dist
+    //! * the estimated CDF at `x`: `f64`, 0 below the smallest outcome
+    let idx: usize = est_dist.partition_point(|(x_i, _)| x_i <= x);
+    match idx {
+        0 => 0.,
+        _ => est_dist[idx - 1].1,
+    }
+}
+
+pub(crate) fn ks_statistic(
+    dist: &(impl ContinuousProbabilityDistribution + ?Sized),
+    cdf: &EmpiricalCdf,
+) -> f64 {
+    //! Computes the exact Kolmogorov-Smirnov statistic by comparing `dist` with both sides of every
+    //! step of the empirical cdf
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, a continuous probability distribution
+    //! * `cdf`: `&EmpiricalCdf`, the empirical cdf of the samples
+    //!
+    //! ## Returns:
+    //! * `f64`, the supremum distance between both cdfs
+    let n: f64 = cdf.len() as f64;
+    cdf.sorted_samples()
         .iter()
-        .filter(|(x_i, _)| x_i <= x)
-        .last()
-        .unwrap()
-        .1
+        .enumerate()
+        .map(|(i, &x)| {
+            let y: f64 = dist.cdf(x);
+            f64::max((i + 1) as f64 / n - y, y - i as f64 / n)
+        })
+        .fold(0., f64::max)
 }
 
 pub fn ks_distance(dist: &impl ContinuousProbabilityDistribution, samples: &Vec<f64>) -> f64 {
-    //! Computes the Kolmogorov-Smirnov distance between the estimated CDF and the true CDF
+    //! Computes the Kolmogorov-Smirnov distance between the estimated CDF and the true CDF, evaluated
+    //! exactly on both sides of every sample
     //!
     //! ## Arguments:
     //! * `dist`: `&impl ContinuousProbabilityDistribution`, a continuous probability distribution
@@ -65,28 +74,216 @@ pub fn ks_distance(dist: &impl ContinuousProbabilityDistribution, samples: &Vec<
     //!
     //! ## Returns:
     //! * `f64`, the Kolmogorov-Smirnov distance between the estimated CDF and the true CDF
-    let cdf: Vec<(f64, f64)> = ks_estimate_continuous_cdf(samples);
-    let max_x: f64 = cdf
-        .iter()
-        .map(|(x, _)| *x)
-        .max_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap();
-    let min_x: f64 = cdf
-        .iter()
-        .map(|(x, _)| *x)
-        .min_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap();
-    let delta: f64 = (max_x - min_x) / NUM_STEPS as f64;
-    let xs: Vec<f64> = (1..NUM_STEPS)
-        .map(|i| min_x + i as f64 * delta)
-        .collect::<Vec<f64>>();
-    // compute supremum metric
-    let max_diff: f64 = xs
+    ks_statistic(dist, &EmpiricalCdf::new(samples))
+}
+
+pub fn ks_test(dist: &impl ContinuousProbabilityDistribution, samples: &[f64]) -> KsTest {
+    //! Performs a one-sample Kolmogorov-Smirnov test of `samples` against `dist`
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, hypothesised distribution
+    //! * `samples`: `&[f64]`, samples to test
+    //!
+    //! ## Returns:
+    //! * `KsTest`, the statistic D and its p-value
+    //!
+    //! ## Panics:
+    //! * if `samples` is empty or contains NaN
+    try_ks_test(dist, samples).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_ks_test(
+    dist: &impl ContinuousProbabilityDistribution,
+    samples: &[f64],
+) -> Result<KsTest, KoError> {
+    //! Performs a one-sample Kolmogorov-Smirnov test of `samples` against `dist`. The p-value is
+    //! exact for at most `KS_EXACT_MAX_SAMPLES` samples and asymptotic otherwise.
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, hypothesised distribution
+    //! * `samples`: `&[f64]`, samples to test
+    //!
+    //! ## Returns:
+    //! * `Result<KsTest, KoError>`, the statistic D and its p-value
+    let cdf: EmpiricalCdf = EmpiricalCdf::try_new(samples)?;
+    let statistic: f64 = ks_statistic(dist, &cdf);
+    Ok(KsTest {
+        statistic,
+        p_value: kolmogorov_p_value(statistic, cdf.len()),
+    })
+}
+
+pub fn ks_two_sample_test(samples_x: &[f64], samples_y: &[f64]) -> KsTest {
+    //! Performs a two-sample Kolmogorov-Smirnov test of whether `samples_x` and `samples_y` come
+    //! from the same distribution
+    //!
+    //! ## Arguments:
+    //! * `samples_x`: `&[f64]`, first batch of samples
+    //! * `samples_y`: `&[f64]`, second batch of samples
+    //!
+    //! ## Returns:
+    //! * `KsTest`, the statistic D and its p-value
+    //!
+    //! ## Panics:
+    //! * if either batch is empty or contains NaN
+    try_ks_two_sample_test(samples_x, samples_y).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_ks_two_sample_test(samples_x: &[f64], samples_y: &[f64]) -> Result<KsTest, KoError> {
+    //! Performs a two-sample Kolmogorov-Smirnov test of whether `samples_x` and `samples_y` come
+    //! from the same distribution. The statistic is the supremum distance between both empirical
+    //! cdfs, the p-value is asymptotic in the effective sample size n m / (n + m) with the
+    //! small-sample correction of Stephens.
+    //!
+    //! ## Arguments:
+    //! * `samples_x`: `&[f64]`, first batch of samples
+    //! * `samples_y`: `&[f64]`, second batch of samples
+    //!
+    //! ## Returns:
+    //! * `Result<KsTest, KoError>`, the statistic D and its p-value
+    let cdf_x: EmpiricalCdf = EmpiricalCdf::try_new(samples_x)?;
+    let cdf_y: EmpiricalCdf = EmpiricalCdf::try_new(samples_y)?;
+    // both cdfs are right-continuous step functions, so the supremum is attained at a sample
+    let statistic: f64 = cdf_x
+        .sorted_samples()
         .iter()
-        .map(|&x| (dist.cdf(x) - ks_evaluate_estimated_cdf(&cdf, &x)).abs())
-        .max_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap();
-    max_diff
+        .chain(cdf_y.sorted_samples().iter())
+        .map(|&x| (cdf_x.evaluate(x) - cdf_y.evaluate(x)).abs())
+        .fold(0., f64::max);
+    let n: f64 = cdf_x.len() as f64;
+    let m: f64 = cdf_y.len() as f64;
+    let effective_n: f64 = (n * m / (n + m)).sqrt();
+    let p_value: f64 = kolmogorov_survival((effective_n + 0.12 + 0.11 / effective_n) * statistic);
+    Ok(KsTest { statistic, p_value })
+}
+
+pub fn kolmogorov_p_value(statistic: f64, n: usize) -> f64 {
+    //! Computes P(D_n >= `statistic`) for the one-sample Kolmogorov-Smirnov statistic D_n
+    //!
+    //! ## Arguments:
+    //! * `statistic`: `f64`, observed statistic
+    //! * `n`: `usize`, number of samples
+    //!
+    //! ## Returns:
+    //! * `f64`, exact for `n` <= `KS_EXACT_MAX_SAMPLES`, asymptotic otherwise
+    if statistic <= 0. {
+        return 1.;
+    }
+    if statistic >= 1. {
+        return 0.;
+    }
+    let p_value: f64 = if n <= KS_EXACT_MAX_SAMPLES {
+        1. - kolmogorov_exact_cdf(statistic, n)
+    } else {
+        kolmogorov_survival((n as f64).sqrt() * statistic)
+    };
+    p_value.clamp(0., 1.)
+}
+
+pub fn kolmogorov_survival(lambda: f64) -> f64 {
+    //! Computes the survival function of the limiting Kolmogorov distribution,
+    //! P(K > `lambda`) = 2 sum_{k>=1} (-1)^{k-1} exp(-2 k^2 `lambda`^2)
+    //!
+    //! ## Arguments:
+    //! * `lambda`: `f64`, scaled statistic sqrt(n) D
+    //!
+    //! ## Returns:
+    //! * `f64`, P(K > `lambda`)
+    if lambda <= 0. {
+        return 1.;
+    }
+    if lambda < 1. {
+        // the alternating series converges slowly for small lambda, use the dual representation
+        let factor: f64 = -PI.powi(2) / (8. * lambda.powi(2));
+        let cdf: f64 = (2. * PI).sqrt() / lambda
+            * (1..=KOLMOGOROV_SERIES_TERMS)
+                .map(|k| (factor * ((2 * k - 1) as f64).powi(2)).exp())
+                .sum::<f64>();
+        return (1. - cdf).clamp(0., 1.);
+    }
+    let survival: f64 = 2.
+        * (1..=KOLMOGOROV_SERIES_TERMS)
+            .map(|k| {
+                let sign: f64 = if k % 2 == 1 { 1. } else { -1. };
+                sign * (-2. * (k as f64).powi(2) * lambda.powi(2)).exp()
+            })
+            .sum::<f64>();
+    survival.clamp(0., 1.)
+}
+
+fn kolmogorov_exact_cdf(statistic: f64, n: usize) -> f64 {
+    //! Computes P(D_n < `statistic`) exactly by the method of Marsaglia, Tsang and Wang (2003)
+    //!
+    //! ## Arguments:
+    //! * `statistic`: `f64`, observed statistic in (0, 1)
+    //! * `n`: `usize`, number of samples
+    //!
+    //! ## Returns:
+    //! * `f64`, P(D_n < `statistic`)
+    let nd: f64 = n as f64 * statistic;
+    let k: usize = nd.floor() as usize + 1;
+    let m: usize = 2 * k - 1;
+    let h: f64 = k as f64 - nd;
+    let entry = |i: usize, j: usize| -> f64 {
+        if i + 1 < j {
+            return 0.;
+        }
+        let mut value: f64 = 1.;
+        if j == 0 {
+            value -= h.powi(i as i32 + 1);
+        }
+        if i == m - 1 {
+            value -= h.powi((m - j) as i32);
+            if j == 0 && 2. * h - 1. > 0. {
+                value += (2. * h - 1.).powi(m as i32);
+            }
+        }
+        // divide by (i - j + 1)!
+        (1..=(i + 1 - j)).fold(value, |acc, g| acc / g as f64)
+    };
+    let matrix: Vec<Vec<f64>> = (0..m)
+        .map(|i| (0..m).map(|j| entry(i, j)).collect())
+        .collect();
+    let power: Vec<Vec<f64>> = matrix_power(&matrix, n);
+    let mut cdf: f64 = power[k - 1][k - 1];
+    for i in 1..=n {
+        cdf *= i as f64 / n as f64;
+    }
+    cdf
+}
+
+fn matrix_power(matrix: &[Vec<f64>], exponent: usize) -> Vec<Vec<f64>> {
+    //! Raises a square matrix to a non-negative integer power by repeated squaring
+    //!
+    //! ## Arguments:
+    //! * `matrix`: `&[Vec<f64>]`, square matrix
+    //! * `exponent`: `usize`
+    //!
+    //! ## Returns:
+    //! * `Vec<Vec<f64>>`, `matrix` to the power of `exponent`
+    let m: usize = matrix.len();
+    let multiply = |a: &[Vec<f64>], b: &[Vec<f64>]| -> Vec<Vec<f64>> {
+        (0..m)
+            .map(|i| {
+                (0..m)
+                    .map(|j| (0..m).map(|l| a[i][l] * b[l][j]).sum::<f64>())
+                    .collect()
+            })
+            .collect()
+    };
+    let mut result: Vec<Vec<f64>> = (0..m)
+        .map(|i| (0..m).map(|j| if i == j { 1. } else { 0. }).collect())
+        .collect();
+    let mut base: Vec<Vec<f64>> = matrix.to_vec();
+    let mut exponent: usize = exponent;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = multiply(&result, &base);
+        }
+        base = multiply(&base, &base);
+        exponent /= 2;
+    }
+    result
 }
 
 pub fn ks_validate_continuous_cdf(
@@ -1,10 +1,187 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::ContinuousProbabilityDistribution;
 use crate::probability::discrete_distribution::DiscreteProbabilityDistribution;
+use crate::probability::induction::goodness_of_fit::GoodnessOfFitMetric;
 use crate::probability::information::discrete_entropy;
 use crate::probability::information_unit::InformationUnit;
-use crate::probability::utils::softmax::softmax;
+use crate::probability::utils::softmax::softmax_with_temperature;
 
 const EPSILON: f64 = 1e-10;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreMap {
+    Reciprocal,
+    Negative,
+    NegativeLog,
+    Likelihood,
+}
+
+impl ScoreMap {
+    pub fn score(&self, estimation_error: f64) -> f64 {
+        //! Maps an estimation error to a score, larger scores indicating a better model.
+        //!
+        //! ## Arguments:
+        //! * `estimation_error`: `f64`, estimation error of a model; for `Likelihood` the negative
+        //!   log-likelihood of the model, so that decision probabilities are proportional to the
+        //!   likelihoods raised to the inverse temperature
+        //!
+        //! ## Returns:
+        //! * `f64`, the score
+        match self {
+            ScoreMap::Reciprocal => 1. / (estimation_error + EPSILON),
+            ScoreMap::Negative => -estimation_error,
+            ScoreMap::NegativeLog => -(estimation_error + EPSILON).ln(),
+            ScoreMap::Likelihood => -estimation_error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecisionScoring {
+    pub map: ScoreMap,
+    pub inverse_temperature: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Decision {
+    pub distribution: DiscreteProbabilityDistribution<i32>,
+    pub entropy: InformationUnit,
+}
+
+impl Default for DecisionScoring {
+    fn default() -> Self {
+        Self {
+            map: ScoreMap::Reciprocal,
+            inverse_temperature: 1.,
+        }
+    }
+}
+
+impl DecisionScoring {
+    pub fn new(map: ScoreMap, inverse_temperature: f64) -> Self {
+        //! Creates a decision scoring turning estimation errors into decision probabilities
+        //! softmax(β map(error)).
+        //!
+        //! ## Arguments:
+        //! * `map`: `ScoreMap`, maps estimation errors to scores
+        //! * `inverse_temperature`: `f64`, inverse temperature β of the softmax
+        //!
+        //! ## Returns:
+        //! * `DecisionScoring`
+        //!
+        //! ## Panics:
+        //! * if `inverse_temperature` is not positive and finite
+        Self::try_new(map, inverse_temperature).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(map: ScoreMap, inverse_temperature: f64) -> Result<Self, KoError> {
+        //! Creates a decision scoring turning estimation errors into decision probabilities
+        //! softmax(β map(error)).
+        //!
+        //! ## Arguments:
+        //! * `map`: `ScoreMap`, maps estimation errors to scores
+        //! * `inverse_temperature`: `f64`, inverse temperature β of the softmax
+        //!
+        //! ## Returns:
+        //! * `Result<DecisionScoring, KoError>`
+        if !(inverse_temperature > 0. && inverse_temperature.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "inverse temperature must be positive and finite".to_string(),
+            ));
+        }
+        Ok(Self {
+            map,
+            inverse_temperature,
+        })
+    }
+
+    pub fn with_temperature(map: ScoreMap, temperature: f64) -> Self {
+        //! ## Arguments:
+        //! * `map`: `ScoreMap`, maps estimation errors to scores
+        //! * `temperature`: `f64`, temperature T = 1 / β of the softmax
+        //!
+        //! ## Returns:
+        //! * `DecisionScoring`
+        //!
+        //! ## Panics:
+        //! * if `temperature` is not positive and finite
+        Self::new(map, 1. / temperature)
+    }
+
+    pub fn decision_probabilities(&self, estimation_errors: &[f64]) -> Vec<f64> {
+        //! ## Arguments:
+        //! * `estimation_errors`: `&[f64]`, estimation errors for every possible probability
+        //!   distribution
+        //!
+        //! ## Returns:
+        //! * `Vec<f64>`, the decision probabilities
+        let scores: Vec<f64> = estimation_errors
+            .iter()
+            .map(|&e| self.map.score(e))
+            .collect();
+        softmax_with_temperature(&scores, self.inverse_temperature)
+    }
+
+    pub fn decide(&self, estimation_errors: &[f64]) -> Decision {
+        //! Computes the decision distribution and its entropy.
+        //!
+        //! ## Arguments:
+        //! * `estimation_errors`: `&[f64]`, estimation errors for every possible probability
+        //!   distribution
+        //!
+        //! ## Returns:
+        //! * `Decision`, the decision distribution together with its entropy
+        let distribution: DiscreteProbabilityDistribution<i32> =
+            DiscreteProbabilityDistribution::multinomial(
+                self.decision_probabilities(estimation_errors),
+            );
+        let entropy: InformationUnit = discrete_entropy(&distribution);
+        Decision {
+            distribution,
+            entropy,
+        }
+    }
+
+    pub fn decision_probabilities_from_log_likelihoods(&self, log_likelihoods: &[f64]) -> Vec<f64> {
+        //! Scores the negated `log_likelihoods` as estimation errors, which with
+        //! `ScoreMap::Likelihood` weights every distribution by its likelihood.
+        //!
+        //! ## Arguments:
+        //! * `log_likelihoods`: `&[f64]`, log-likelihoods of every possible probability
+        //!   distribution
+        //!
+        //! ## Returns:
+        //! * `Vec<f64>`, the decision probabilities
+        let estimation_errors: Vec<f64> = log_likelihoods.iter().map(|l| -l).collect();
+        self.decision_probabilities(&estimation_errors)
+    }
+
+    pub fn decide_from_log_likelihoods(&self, log_likelihoods: &[f64]) -> Decision {
+        //! Computes the decision distribution and its entropy from log-likelihoods.
+        //!
+        //! ## Arguments:
+        //! * `log_likelihoods`: `&[f64]`, log-likelihoods of every possible probability
+        //!   distribution
+        //!
+        //! ## Returns:
+        //! * `Decision`, the decision distribution together with its entropy
+        let estimation_errors: Vec<f64> = log_likelihoods.iter().map(|l| -l).collect();
+        self.decide(&estimation_errors)
+    }
+}
+
+pub fn compute_decision_probabilities(estimation_errors: &[f64]) -> Vec<f64> {
+    //! Computes the probability of deciding for every possible probability distribution given
+    //! their estimation errors, as the softmax of the reciprocal errors.
+    //!
+    //! ## Arguments:
+    //! * `estimation_errors`: `&[f64]`, estimation errors for every possible probability distribution
+    //!
+    //! ## Returns:
+    //! * `Vec<f64>`, the decision probabilities
+    DecisionScoring::default().decision_probabilities(estimation_errors)
+}
+
 pub fn compute_decision_entropy(estimation_errors: &Vec<f64>) -> InformationUnit {
     //! Computes the decision entropy given a set of estimation errors (e.g. Kolmogorov-Smirnov Distances).
     //!
@@ -13,13 +190,30 @@ pub fn compute_decision_entropy(estimation_errors: &Vec<f64>) -> InformationUnit
     //!
     //! ## Returns:
     //! * `f64`, the decision entropy
-    let decision_probabilities: Vec<f64> = softmax(
-        &estimation_errors
-            .iter()
-            .map(|x| 1. / (x + EPSILON))
-            .collect(),
-    );
+    let decision_probabilities: Vec<f64> = compute_decision_probabilities(estimation_errors);
     let decision_distribution: DiscreteProbabilityDistribution<i32> =
         DiscreteProbabilityDistribution::multinomial(decision_probabilities);
     discrete_entropy(&decision_distribution)
 }
+
+pub fn compute_metric_decision_entropy(
+    metric: GoodnessOfFitMetric,
+    candidates: &[&dyn ContinuousProbabilityDistribution],
+    samples: &[f64],
+) -> InformationUnit {
+    //! Computes the decision entropy between candidate distributions, using `metric` to measure
+    //! the estimation error of every candidate.
+    //!
+    //! ## Arguments:
+    //! * `metric`: `GoodnessOfFitMetric`, statistic used as estimation error
+    //! * `candidates`: `&[&dyn ContinuousProbabilityDistribution]`, fitted candidate distributions
+    //! * `samples`: `&[f64]`, samples the candidates were fitted to
+    //!
+    //! ## Returns:
+    //! * `InformationUnit`, the decision entropy
+    let estimation_errors: Vec<f64> = candidates
+        .iter()
+        .map(|dist| metric.estimation_error(*dist, samples))
+        .collect();
+    compute_decision_entropy(&estimation_errors)
+}
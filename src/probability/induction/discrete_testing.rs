@@ -0,0 +1,185 @@
+use crate::error::KoError;
+use crate::probability::discrete_distribution::DiscreteProbabilityDistribution;
+use statrs::function::gamma::gamma_ur;
+
+pub const MIN_EXPECTED_COUNT: f64 = 5.;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ChiSquareTest {
+    pub statistic: f64,
+    pub degrees_of_freedom: usize,
+    pub p_value: f64,
+}
+
+fn pooled_counts(
+    dist: &DiscreteProbabilityDistribution<i32>,
+    samples: &[i32],
+) -> Result<Option<Vec<(f64, f64)>>, KoError> {
+    //! Counts the observed and expected samples per outcome and pools adjacent outcomes until
+    //! every bin expects at least `MIN_EXPECTED_COUNT` samples
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&DiscreteProbabilityDistribution<i32>`, hypothesised distribution
+    //! * `samples`: `&[i32]`, observed samples
+    //!
+    //! ## Returns:
+    //! * `Result<Option<Vec<(f64, f64)>>, KoError>`, (observed, expected) counts per bin, or
+    //!   `None` if a sample lies outside the support of `dist`
+    if samples.is_empty() {
+        return Err(KoError::EmptySamples);
+    }
+    let n: f64 = samples.len() as f64;
+    let mut outcomes: Vec<(i32, f64)> = dist
+        .outcomes
+        .iter()
+        .zip(dist.probabilities.iter())
+        .filter(|(_, &p)| p > 0.)
+        .map(|(&x, &p)| (x, p))
+        .collect();
+    outcomes.sort_by_key(|&(x, _)| x);
+    let mut counts: Vec<f64> = vec![0.; outcomes.len()];
+    for x in samples {
+        match outcomes.binary_search_by_key(x, |&(y, _)| y) {
+            Ok(idx) => counts[idx] += 1.,
+            Err(_) => return Ok(None),
+        }
+    }
+
+    let mut bins: Vec<(f64, f64)> = Vec::new();
+    let mut bin: (f64, f64) = (0., 0.);
+    for (&(_, p), &count) in outcomes.iter().zip(counts.iter()) {
+        bin = (bin.0 + count, bin.1 + n * p);
+        if bin.1 >= MIN_EXPECTED_COUNT {
+            bins.push(bin);
+            bin = (0., 0.);
+        }
+    }
+    // the remaining upper outcomes are merged into the last full bin
+    match bins.last_mut() {
+        Some(last) => *last = (last.0 + bin.0, last.1 + bin.1),
+        None => bins.push(bin),
+    }
+    if bins.len() < 2 {
+        return Err(KoError::InvalidParameter(format!(
+            "at least two bins expecting {} samples each are required",
+            MIN_EXPECTED_COUNT
+        )));
+    }
+    Ok(Some(bins))
+}
+
+fn chi_square_from_statistic(statistic: f64, num_bins: usize) -> ChiSquareTest {
+    //! ## Arguments:
+    //! * `statistic`: `f64`, asymptotically chi-square distributed statistic
+    //! * `num_bins`: `usize`, number of pooled bins
+    //!
+    //! ## Returns:
+    //! * `ChiSquareTest`, the statistic with its degrees of freedom and p-value
+    let degrees_of_freedom: usize = num_bins - 1;
+    let p_value: f64 = if statistic.is_infinite() {
+        0.
+    } else if statistic <= 0. {
+        1.
+    } else {
+        gamma_ur(degrees_of_freedom as f64 / 2., statistic / 2.)
+    };
+    ChiSquareTest {
+        statistic,
+        degrees_of_freedom,
+        p_value,
+    }
+}
+
+pub fn chi_square_test(
+    dist: &DiscreteProbabilityDistribution<i32>,
+    samples: &[i32],
+) -> ChiSquareTest {
+    //! Performs Pearson's chi-square goodness-of-fit test of `samples` against `dist`
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&DiscreteProbabilityDistribution<i32>`, hypothesised distribution
+    //! * `samples`: `&[i32]`, observed samples
+    //!
+    //! ## Returns:
+    //! * `ChiSquareTest`, the statistic with its degrees of freedom and p-value
+    //!
+    //! ## Panics:
+    //! * if `samples` is empty or fewer than two bins remain after pooling
+    try_chi_square_test(dist, samples).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_chi_square_test(
+    dist: &DiscreteProbabilityDistribution<i32>,
+    samples: &[i32],
+) -> Result<ChiSquareTest, KoError> {
+    //! Performs Pearson's chi-square goodness-of-fit test of `samples` against `dist`. Adjacent
+    //! outcomes are pooled until every bin expects at least `MIN_EXPECTED_COUNT` samples. A
+    //! sample outside the support of `dist` yields an infinite statistic.
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&DiscreteProbabilityDistribution<i32>`, hypothesised distribution
+    //! * `samples`: `&[i32]`, observed samples
+    //!
+    //! ## Returns:
+    //! * `Result<ChiSquareTest, KoError>`, the statistic with its degrees of freedom and p-value
+    let bins: Vec<(f64, f64)> = match pooled_counts(dist, samples)? {
+        Some(bins) => bins,
+        None => {
+            return Ok(chi_square_from_statistic(
+                f64::INFINITY,
+                dist.outcomes.len(),
+            ))
+        }
+    };
+    let statistic: f64 = bins
+        .iter()
+        .map(|(observed, expected)| (observed - expected).powi(2) / expected)
+        .sum::<f64>();
+    Ok(chi_square_from_statistic(statistic, bins.len()))
+}
+
+pub fn g_test(dist: &DiscreteProbabilityDistribution<i32>, samples: &[i32]) -> ChiSquareTest {
+    //! Performs a G-test (log-likelihood-ratio test) of `samples` against `dist`
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&DiscreteProbabilityDistribution<i32>`, hypothesised distribution
+    //! * `samples`: `&[i32]`, observed samples
+    //!
+    //! ## Returns:
+    //! * `ChiSquareTest`, the statistic with its degrees of freedom and p-value
+    //!
+    //! ## Panics:
+    //! * if `samples` is empty or fewer than two bins remain after pooling
+    try_g_test(dist, samples).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_g_test(
+    dist: &DiscreteProbabilityDistribution<i32>,
+    samples: &[i32],
+) -> Result<ChiSquareTest, KoError> {
+    //! Performs a G-test of `samples` against `dist`, G = 2 sum O ln(O / E). Bins are pooled as
+    //! for `try_chi_square_test` and G is asymptotically chi-square distributed.
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&DiscreteProbabilityDistribution<i32>`, hypothesised distribution
+    //! * `samples`: `&[i32]`, observed samples
+    //!
+    //! ## Returns:
+    //! * `Result<ChiSquareTest, KoError>`, the statistic with its degrees of freedom and p-value
+    let bins: Vec<(f64, f64)> = match pooled_counts(dist, samples)? {
+        Some(bins) => bins,
+        None => {
+            return Ok(chi_square_from_statistic(
+                f64::INFINITY,
+                dist.outcomes.len(),
+            ))
+        }
+    };
+    let statistic: f64 = 2.
+        * bins
+            .iter()
+            .filter(|(observed, _)| *observed > 0.)
+            .map(|(observed, expected)| observed * (observed / expected).ln())
+            .sum::<f64>();
+    Ok(chi_square_from_statistic(statistic, bins.len()))
+}
@@ -0,0 +1,145 @@
+use crate::error::KoError;
+
+#[derive(Debug, Clone)]
+pub struct EmpiricalCdf {
+    sorted_samples: Vec<f64>,
+}
+
+impl EmpiricalCdf {
+    pub fn new(samples: &[f64]) -> Self {
+        //! Creates the empirical cumulative distribution function of `samples`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from which to build the cdf
+        //!
+        //! ## Returns:
+        //! * `EmpiricalCdf`
+        //!
+        //! ## Panics:
+        //! * if `samples` is empty or contains NaN
+        Self::try_new(samples).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(samples: &[f64]) -> Result<Self, KoError> {
+        //! Creates the empirical cumulative distribution function of `samples` in O(n log n).
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples from which to build the cdf
+        //!
+        //! ## Returns:
+        //! * `Result<EmpiricalCdf, KoError>`
+        if samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
+        if samples.iter().any(|x| x.is_nan()) {
+            return Err(KoError::InvalidParameter(
+                "samples must not contain NaN".to_string(),
+            ));
+        }
+        let mut sorted_samples: Vec<f64> = samples.to_vec();
+        sorted_samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Ok(Self { sorted_samples })
+    }
+
+    pub fn len(&self) -> usize {
+        //! ## Returns:
+        //! * `usize`, number of samples
+        self.sorted_samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        //! ## Returns:
+        //! * `bool`, true if there are no samples
+        self.sorted_samples.is_empty()
+    }
+
+    pub fn sorted_samples(&self) -> &[f64] {
+        //! ## Returns:
+        //! * `&[f64]`, the samples in ascending order
+        &self.sorted_samples
+    }
+
+    pub fn min(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, smallest sample
+        self.sorted_samples[0]
+    }
+
+    pub fn max(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, largest sample
+        self.sorted_samples[self.len() - 1]
+    }
+
+    pub fn evaluate(&self, x: f64) -> f64 {
+        //! Evaluates the empirical cdf, i.e. the fraction of samples less than or equal to `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the cdf
+        //!
+        //! ## Returns:
+        //! * cdf(`x`): `f64`, 0 below the smallest sample
+        self.sorted_samples.partition_point(|&y| y <= x) as f64 / self.len() as f64
+    }
+
+    pub fn evaluate_left(&self, x: f64) -> f64 {
+        //! Evaluates the left limit of the empirical cdf, i.e. the fraction of samples strictly
+        //! less than `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the left limit
+        //!
+        //! ## Returns:
+        //! * cdf(`x`-): `f64`
+        self.sorted_samples.partition_point(|&y| y < x) as f64 / self.len() as f64
+    }
+
+    pub fn quantile(&self, p: f64) -> f64 {
+        //! Computes the smallest sample `x` with cdf(`x`) >= `p`.
+        //!
+        //! ## Arguments:
+        //! * `p`: `f64`, probability in `[0, 1]`
+        //!
+        //! ## Returns:
+        //! * quantile(`p`): `f64`
+        assert!((0. ..=1.).contains(&p), "p must lie in [0, 1]");
+        let n: usize = self.len();
+        // ceil(p n) is only a first guess, the product may round across an integer
+        let mut k: usize = ((p * n as f64).ceil() as usize).clamp(1, n);
+        while k > 1 && (k - 1) as f64 / n as f64 >= p {
+            k -= 1;
+        }
+        while k < n && (k as f64 / n as f64) < p {
+            k += 1;
+        }
+        self.sorted_samples[k - 1]
+    }
+
+    pub fn steps(&self) -> Vec<(f64, f64)> {
+        //! ## Returns:
+        //! * `Vec<(f64, f64)>`, (x, cdf(x)) for every distinct sample x in ascending order
+        let n: f64 = self.len() as f64;
+        let mut steps: Vec<(f64, f64)> = Vec::new();
+        for (idx, &x) in self.sorted_samples.iter().enumerate() {
+            match steps.last_mut() {
+                Some(step) if step.0 == x => step.1 = (idx + 1) as f64 / n,
+                _ => steps.push((x, (idx + 1) as f64 / n)),
+            }
+        }
+        steps
+    }
+
+    pub fn plot_data(&self) -> Vec<(f64, f64)> {
+        //! ## Returns:
+        //! * `Vec<(f64, f64)>`, corner points of the staircase of the cdf, suitable for
+        //!   `plot_data`
+        let mut data: Vec<(f64, f64)> = Vec::new();
+        let mut previous: f64 = 0.;
+        for (x, y) in self.steps() {
+            data.push((x, previous));
+            data.push((x, y));
+            previous = y;
+        }
+        data
+    }
+}
@@ -0,0 +1,291 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::ContinuousProbabilityDistribution;
+use crate::probability::induction::continuous_testing::ks_statistic;
+use crate::probability::induction::empirical_cdf::EmpiricalCdf;
+use std::f64::consts::PI;
+
+const CVM_SERIES_TERMS: usize = 50;
+const BESSEL_STEP: f64 = 0.01;
+const AD_CDF_EPSILON: f64 = 1e-10; // keeps fits whose support starts at a sample finite
+
+#[derive(Debug, Clone, Copy)]
+pub struct GoodnessOfFitTest {
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoodnessOfFitMetric {
+    KolmogorovSmirnov,
+    AndersonDarling,
+    CramerVonMises,
+}
+
+impl GoodnessOfFitMetric {
+    pub fn estimation_error(
+        &self,
+        dist: &(impl ContinuousProbabilityDistribution + ?Sized),
+        samples: &[f64],
+    ) -> f64 {
+        //! Computes the statistic of the metric, usable as an estimation error for
+        //! `compute_decision_entropy`.
+        //!
+        //! ## Arguments:
+        //! * `dist`: `&impl ContinuousProbabilityDistribution`, candidate distribution
+        //! * `samples`: `&[f64]`, samples the candidate was fitted to
+        //!
+        //! ## Returns:
+        //! * `f64`, the statistic, smaller values indicating a better fit
+        //!
+        //! ## Panics:
+        //! * if `samples` is empty or contains NaN
+        let cdf: EmpiricalCdf = EmpiricalCdf::new(samples);
+        match self {
+            GoodnessOfFitMetric::KolmogorovSmirnov => ks_statistic(dist, &cdf),
+            GoodnessOfFitMetric::AndersonDarling => anderson_darling_statistic(dist, &cdf),
+            GoodnessOfFitMetric::CramerVonMises => cramer_von_mises_statistic(dist, &cdf),
+        }
+    }
+}
+
+fn anderson_darling_statistic(
+    dist: &(impl ContinuousProbabilityDistribution + ?Sized),
+    cdf: &EmpiricalCdf,
+) -> f64 {
+    //! Computes A^2 = -n - 1/n sum_i (2i - 1) (ln F(x_i) + ln(1 - F(x_{n+1-i}))), with F clamped
+    //! to [eps, 1 - eps] so that samples on the boundary of the support, as produced by fits that
+    //! place x_min at the sample minimum, do not make the statistic infinite
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, hypothesised distribution
+    //! * `cdf`: `&EmpiricalCdf`, the empirical cdf of the samples
+    //!
+    //! ## Returns:
+    //! * `f64`, the Anderson-Darling statistic
+    let sorted: &[f64] = cdf.sorted_samples();
+    let n: usize = sorted.len();
+    let sum: f64 = (0..n)
+        .map(|i| {
+            // the survival is evaluated in log space to keep the upper tail accurate
+            let log_cdf: f64 = dist.cdf(sorted[i]).max(AD_CDF_EPSILON).ln();
+            let log_survival: f64 = dist
+                .log_survival(sorted[n - 1 - i])
+                .max(AD_CDF_EPSILON.ln());
+            (2 * i + 1) as f64 * (log_cdf + log_survival)
+        })
+        .sum::<f64>();
+    -(n as f64) - sum / n as f64
+}
+
+fn cramer_von_mises_statistic(
+    dist: &(impl ContinuousProbabilityDistribution + ?Sized),
+    cdf: &EmpiricalCdf,
+) -> f64 {
+    //! Computes W^2 = 1 / (12 n) + sum_i (F(x_i) - (2i - 1) / (2n))^2
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, hypothesised distribution
+    //! * `cdf`: `&EmpiricalCdf`, the empirical cdf of the samples
+    //!
+    //! ## Returns:
+    //! * `f64`, the Cramér-von Mises statistic
+    let n: f64 = cdf.len() as f64;
+    let sum: f64 = cdf
+        .sorted_samples()
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| (dist.cdf(x) - (2 * i + 1) as f64 / (2. * n)).powi(2))
+        .sum::<f64>();
+    1. / (12. * n) + sum
+}
+
+pub fn anderson_darling_test(
+    dist: &impl ContinuousProbabilityDistribution,
+    samples: &[f64],
+) -> GoodnessOfFitTest {
+    //! Performs an Anderson-Darling test of `samples` against `dist`
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, hypothesised distribution
+    //! * `samples`: `&[f64]`, samples to test
+    //!
+    //! ## Returns:
+    //! * `GoodnessOfFitTest`, the statistic A^2 and its p-value
+    //!
+    //! ## Panics:
+    //! * if `samples` is empty or contains NaN
+    try_anderson_darling_test(dist, samples).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_anderson_darling_test(
+    dist: &impl ContinuousProbabilityDistribution,
+    samples: &[f64],
+) -> Result<GoodnessOfFitTest, KoError> {
+    //! Performs an Anderson-Darling test of `samples` against `dist`. The statistic weights the
+    //! squared cdf difference by 1 / (F (1 - F)), which makes it sensitive in the tails. The
+    //! p-value assumes a fully specified `dist` and follows Marsaglia and Marsaglia (2004).
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, hypothesised distribution
+    //! * `samples`: `&[f64]`, samples to test
+    //!
+    //! ## Returns:
+    //! * `Result<GoodnessOfFitTest, KoError>`, the statistic A^2 and its p-value
+    let cdf: EmpiricalCdf = EmpiricalCdf::try_new(samples)?;
+    let statistic: f64 = anderson_darling_statistic(dist, &cdf);
+    Ok(GoodnessOfFitTest {
+        statistic,
+        p_value: anderson_darling_p_value(statistic, cdf.len()),
+    })
+}
+
+pub fn cramer_von_mises_test(
+    dist: &impl ContinuousProbabilityDistribution,
+    samples: &[f64],
+) -> GoodnessOfFitTest {
+    //! Performs a Cramér-von Mises test of `samples` against `dist`
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, hypothesised distribution
+    //! * `samples`: `&[f64]`, samples to test
+    //!
+    //! ## Returns:
+    //! * `GoodnessOfFitTest`, the statistic W^2 and its p-value
+    //!
+    //! ## Panics:
+    //! * if `samples` is empty or contains NaN
+    try_cramer_von_mises_test(dist, samples).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_cramer_von_mises_test(
+    dist: &impl ContinuousProbabilityDistribution,
+    samples: &[f64],
+) -> Result<GoodnessOfFitTest, KoError> {
+    //! Performs a Cramér-von Mises test of `samples` against `dist`. The p-value assumes a fully
+    //! specified `dist` and evaluates the limiting distribution at Stephens' finite-sample
+    //! modification of the statistic.
+    //!
+    //! ## Arguments:
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`, hypothesised distribution
+    //! * `samples`: `&[f64]`, samples to test
+    //!
+    //! ## Returns:
+    //! * `Result<GoodnessOfFitTest, KoError>`, the statistic W^2 and its p-value
+    let cdf: EmpiricalCdf = EmpiricalCdf::try_new(samples)?;
+    let statistic: f64 = cramer_von_mises_statistic(dist, &cdf);
+    Ok(GoodnessOfFitTest {
+        statistic,
+        p_value: cramer_von_mises_p_value(statistic, cdf.len()),
+    })
+}
+
+pub fn anderson_darling_p_value(statistic: f64, n: usize) -> f64 {
+    //! Computes P(A^2_n >= `statistic`) by the approximation of Marsaglia and Marsaglia (2004)
+    //!
+    //! ## Arguments:
+    //! * `statistic`: `f64`, observed statistic
+    //! * `n`: `usize`, number of samples
+    //!
+    //! ## Returns:
+    //! * `f64`, the p-value
+    if statistic <= 0. {
+        return 1.;
+    }
+    if statistic.is_infinite() {
+        return 0.;
+    }
+    let z: f64 = statistic;
+    let limit: f64 = if z < 2. {
+        (-1.2337141 / z).exp() / z.sqrt()
+            * (2.00012
+                + (0.247105 - (0.0649821 - (0.0347962 - (0.011672 - 0.00168691 * z) * z) * z) * z)
+                    * z)
+    } else {
+        (-(1.0776
+            - (2.30695 - (0.43424 - (0.082433 - (0.008056 - 0.0003146 * z) * z) * z) * z) * z)
+            .exp())
+        .exp()
+    };
+    // finite sample correction
+    let n: f64 = n as f64;
+    let c: f64 = 0.01265 + 0.1757 / n;
+    let correction: f64 = if limit < c {
+        let t: f64 = limit / c;
+        let t: f64 = t.sqrt() * (1. - t) * (49. * t - 102.);
+        t * (0.0037 / n.powi(2) + 0.00078 / n + 0.00006) / n
+    } else if limit < 0.8 {
+        let t: f64 = (limit - c) / (0.8 - c);
+        let t: f64 =
+            -0.00022633 + (6.54034 - (14.6538 - (14.458 - (8.259 - 1.91864 * t) * t) * t) * t) * t;
+        t * (0.04213 / n + 0.01365 / n.powi(2))
+    } else {
+        (-130.2137
+            + (745.2337
+                - (1705.091 - (1950.646 - (1116.360 - 255.7844 * limit) * limit) * limit) * limit)
+                * limit)
+            / n
+    };
+    (1. - limit - correction).clamp(0., 1.)
+}
+
+pub fn cramer_von_mises_p_value(statistic: f64, n: usize) -> f64 {
+    //! Computes P(W^2_n >= `statistic`) from the limiting distribution evaluated at Stephens'
+    //! modification (W^2 - 0.4 / n + 0.6 / n^2) (1 + 1 / n)
+    //!
+    //! ## Arguments:
+    //! * `statistic`: `f64`, observed statistic
+    //! * `n`: `usize`, number of samples
+    //!
+    //! ## Returns:
+    //! * `f64`, the p-value
+    let n: f64 = n as f64;
+    let modified: f64 = (statistic - 0.4 / n + 0.6 / n.powi(2)) * (1. + 1. / n);
+    (1. - cramer_von_mises_limit_cdf(modified)).clamp(0., 1.)
+}
+
+fn cramer_von_mises_limit_cdf(x: f64) -> f64 {
+    //! Computes the cdf of the limiting Cramér-von Mises distribution by the series of Anderson
+    //! and Darling (1952)
+    //!
+    //! ## Arguments:
+    //! * `x`: `f64`, statistic
+    //!
+    //! ## Returns:
+    //! * `f64`, P(W^2 <= `x`)
+    if x <= 0. {
+        return 0.;
+    }
+    let mut coefficient: f64 = 1.;
+    let mut sum: f64 = 0.;
+    for k in 0..CVM_SERIES_TERMS {
+        if k > 0 {
+            // Gamma(k + 1/2) / (Gamma(1/2) k!)
+            coefficient *= (k as f64 - 0.5) / k as f64;
+        }
+        let u: f64 = ((4 * k + 1) as f64).powi(2) / (16. * x);
+        sum += coefficient * ((4 * k + 1) as f64).sqrt() * (-u).exp() * bessel_k(0.25, u);
+    }
+    (sum / (PI * x.sqrt())).clamp(0., 1.)
+}
+
+fn bessel_k(order: f64, z: f64) -> f64 {
+    //! Computes the modified Bessel function of the second kind from its integral representation
+    //! K_v(z) = int_0^inf exp(-z cosh t) cosh(v t) dt
+    //!
+    //! ## Arguments:
+    //! * `order`: `f64`, order v
+    //! * `z`: `f64`, positive argument
+    //!
+    //! ## Returns:
+    //! * `f64`, K_v(`z`)
+    // the integrand is negligible once z cosh t exceeds 50
+    let upper: f64 = (50. / z).max(1.).acosh() + 1.;
+    let num_steps: usize = (upper / BESSEL_STEP).ceil() as usize;
+    let step: f64 = upper / num_steps as f64;
+    let integrand = |t: f64| (-z * t.cosh()).exp() * (order * t).cosh();
+    // the trapezoidal rule converges exponentially fast for this integrand
+    step * (0.5 * integrand(0.)
+        + (1..=num_steps)
+            .map(|i| integrand(i as f64 * step))
+            .sum::<f64>())
+}
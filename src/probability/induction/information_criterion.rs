@@ -0,0 +1,107 @@
+use crate::probability::continuous_distribution::ContinuousProbabilityDistribution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InformationCriterion {
+    Aic,
+    Aicc,
+    Bic,
+}
+
+impl InformationCriterion {
+    pub fn compute(&self, log_likelihood: f64, num_parameters: usize, num_samples: usize) -> f64 {
+        //! Computes the information criterion of a fitted model.
+        //!
+        //! ## Arguments:
+        //! * `log_likelihood`: `f64`, maximised log-likelihood of the model
+        //! * `num_parameters`: `usize`, number of fitted parameters
+        //! * `num_samples`: `usize`, number of samples the model was fitted to
+        //!
+        //! ## Returns:
+        //! * `f64`, the criterion, smaller values indicating a better model
+        match self {
+            InformationCriterion::Aic => aic(log_likelihood, num_parameters),
+            InformationCriterion::Aicc => aicc(log_likelihood, num_parameters, num_samples),
+            InformationCriterion::Bic => bic(log_likelihood, num_parameters, num_samples),
+        }
+    }
+
+    pub fn evaluate(
+        &self,
+        dist: &(impl ContinuousProbabilityDistribution + ?Sized),
+        num_parameters: usize,
+        samples: &[f64],
+    ) -> f64 {
+        //! Computes the information criterion of `dist` fitted to `samples`.
+        //!
+        //! ## Arguments:
+        //! * `dist`: `&impl ContinuousProbabilityDistribution`, fitted distribution
+        //! * `num_parameters`: `usize`, number of parameters fitted to obtain `dist`
+        //! * `samples`: `&[f64]`, samples `dist` was fitted to
+        //!
+        //! ## Returns:
+        //! * `f64`, the criterion, smaller values indicating a better model
+        self.compute(dist.log_likelihood(samples), num_parameters, samples.len())
+    }
+}
+
+pub fn aic(log_likelihood: f64, num_parameters: usize) -> f64 {
+    //! Computes the Akaike information criterion 2k - 2 ln L
+    //!
+    //! ## Arguments:
+    //! * `log_likelihood`: `f64`, maximised log-likelihood ln L
+    //! * `num_parameters`: `usize`, number of fitted parameters k
+    //!
+    //! ## Returns:
+    //! * `f64`, the AIC
+    2. * num_parameters as f64 - 2. * log_likelihood
+}
+
+pub fn aicc(log_likelihood: f64, num_parameters: usize, num_samples: usize) -> f64 {
+    //! Computes the small-sample corrected Akaike information criterion
+    //! AIC + 2k (k + 1) / (n - k - 1)
+    //!
+    //! ## Arguments:
+    //! * `log_likelihood`: `f64`, maximised log-likelihood ln L
+    //! * `num_parameters`: `usize`, number of fitted parameters k
+    //! * `num_samples`: `usize`, number of samples n
+    //!
+    //! ## Returns:
+    //! * `f64`, the AICc, infinite if n <= k + 1
+    if num_samples <= num_parameters + 1 {
+        return f64::INFINITY;
+    }
+    let k: f64 = num_parameters as f64;
+    aic(log_likelihood, num_parameters) + 2. * k * (k + 1.) / (num_samples as f64 - k - 1.)
+}
+
+pub fn bic(log_likelihood: f64, num_parameters: usize, num_samples: usize) -> f64 {
+    //! Computes the Bayesian (Schwarz) information criterion k ln n - 2 ln L
+    //!
+    //! ## Arguments:
+    //! * `log_likelihood`: `f64`, maximised log-likelihood ln L
+    //! * `num_parameters`: `usize`, number of fitted parameters k
+    //! * `num_samples`: `usize`, number of samples n
+    //!
+    //! ## Returns:
+    //! * `f64`, the BIC
+    num_parameters as f64 * (num_samples as f64).ln() - 2. * log_likelihood
+}
+
+pub fn information_criterion_weights(criteria: &[f64]) -> Vec<f64> {
+    //! Computes Akaike weights (Schwarz weights for the BIC) exp(-Δ_i / 2) / sum_j exp(-Δ_j / 2),
+    //! where Δ_i is the difference of the i-th criterion to the smallest one. The weights are the
+    //! approximate posterior probabilities of the models.
+    //!
+    //! ## Arguments:
+    //! * `criteria`: `&[f64]`, information criterion of every model
+    //!
+    //! ## Returns:
+    //! * `Vec<f64>`, the weights, uniform if no criterion is finite
+    let min: f64 = criteria.iter().copied().fold(f64::INFINITY, f64::min);
+    if !min.is_finite() {
+        return vec![1. / criteria.len() as f64; criteria.len()];
+    }
+    let relative_likelihoods: Vec<f64> = criteria.iter().map(|c| (-(c - min) / 2.).exp()).collect();
+    let sum: f64 = relative_likelihoods.iter().sum::<f64>();
+    relative_likelihoods.iter().map(|l| l / sum).collect()
+}
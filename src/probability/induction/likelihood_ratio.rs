@@ -0,0 +1,104 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::ContinuousProbabilityDistribution;
+use libm::erfc;
+
+#[derive(Debug, Clone, Copy)]
+pub struct VuongTest {
+    pub log_likelihood_ratio: f64,
+    pub normalised_ratio: f64,
+    pub p_value: f64,
+}
+
+pub fn vuong_test(
+    dist_x: &impl ContinuousProbabilityDistribution,
+    dist_y: &impl ContinuousProbabilityDistribution,
+    samples: &[f64],
+) -> VuongTest {
+    //! Performs Vuong's normalised log-likelihood-ratio test between two fitted distributions
+    //!
+    //! ## Arguments:
+    //! * `dist_x`: `&impl ContinuousProbabilityDistribution`, first candidate distribution
+    //! * `dist_y`: `&impl ContinuousProbabilityDistribution`, second candidate distribution
+    //! * `samples`: `&[f64]`, samples both distributions were fitted to
+    //!
+    //! ## Returns:
+    //! * `VuongTest`, a positive log-likelihood ratio favours `dist_x`, a negative one `dist_y`
+    //!
+    //! ## Panics:
+    //! * if `samples` is empty, or a sample lies outside the support of both distributions or
+    //!   each distribution excludes a sample the other one explains
+    try_vuong_test(dist_x, dist_y, samples).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_vuong_test(
+    dist_x: &impl ContinuousProbabilityDistribution,
+    dist_y: &impl ContinuousProbabilityDistribution,
+    samples: &[f64],
+) -> Result<VuongTest, KoError> {
+    //! Performs Vuong's normalised log-likelihood-ratio test between two fitted distributions.
+    //! Under the null hypothesis that both fit equally well, the normalised ratio is standard
+    //! normal, which yields the two-sided p-value.
+    //!
+    //! ## Arguments:
+    //! * `dist_x`: `&impl ContinuousProbabilityDistribution`, first candidate distribution
+    //! * `dist_y`: `&impl ContinuousProbabilityDistribution`, second candidate distribution
+    //! * `samples`: `&[f64]`, samples both distributions were fitted to
+    //!
+    //! ## Returns:
+    //! * `Result<VuongTest, KoError>`, a positive log-likelihood ratio favours `dist_x`, a negative
+    //!   one `dist_y`. A distribution excluding a sample the other one explains, or losing by the
+    //!   same ratio on every sample, is rejected with an infinite normalised ratio and p-value 0.
+    //!   `KoError::InvalidParameter` if the ratio is undefined, i.e. a sample lies outside both
+    //!   supports or each distribution excludes a sample the other one explains
+    if samples.is_empty() {
+        return Err(KoError::EmptySamples);
+    }
+    let n: f64 = samples.len() as f64;
+    // pointwise log-likelihood ratios
+    let ratios: Vec<f64> = samples
+        .iter()
+        .map(|&x| dist_x.log_pdf(x) - dist_y.log_pdf(x))
+        .collect();
+    let log_likelihood_ratio: f64 = ratios.iter().sum::<f64>();
+    if log_likelihood_ratio.is_nan() {
+        return Err(KoError::InvalidParameter(
+            "a sample lies outside the support of both distributions, or each distribution \
+             excludes a sample the other one explains"
+                .to_string(),
+        ));
+    }
+    if log_likelihood_ratio.is_infinite() {
+        // one distribution excludes a sample the other one explains
+        return Ok(decisive(log_likelihood_ratio));
+    }
+    let mean: f64 = log_likelihood_ratio / n;
+    let std_dev: f64 = (ratios.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n).sqrt();
+    if std_dev == 0. && mean != 0. {
+        // one distribution is more likely by the same factor on every sample
+        return Ok(decisive(log_likelihood_ratio));
+    }
+    if std_dev == 0. {
+        // the distributions cannot be told apart on these samples
+        return Ok(VuongTest {
+            log_likelihood_ratio,
+            normalised_ratio: 0.,
+            p_value: 1.,
+        });
+    }
+    let normalised_ratio: f64 = log_likelihood_ratio / (std_dev * n.sqrt());
+    Ok(VuongTest {
+        log_likelihood_ratio,
+        normalised_ratio,
+        p_value: erfc(normalised_ratio.abs() / std::f64::consts::SQRT_2),
+    })
+}
+
+fn decisive(log_likelihood_ratio: f64) -> VuongTest {
+    //! ## Returns:
+    //! * `VuongTest` rejecting equal fits outright in favour of the sign of `log_likelihood_ratio`
+    VuongTest {
+        log_likelihood_ratio,
+        normalised_ratio: f64::INFINITY.copysign(log_likelihood_ratio),
+        p_value: 0.,
+    }
+}
@@ -1,2 +1,12 @@
 pub mod continuous_testing;
 pub mod decision_entropy;
+pub mod discrete_testing;
+pub mod empirical_cdf;
+pub mod goodness_of_fit;
+pub mod information_criterion;
+pub mod likelihood_ratio;
+pub mod model_averaging;
+pub mod model_selection;
+pub mod power_law_fitting;
+pub mod sequential_selection;
+pub mod sequential_testing;
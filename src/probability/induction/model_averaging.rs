@@ -0,0 +1,201 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::ContinuousProbabilityDistribution;
+use crate::probability::discrete_distribution::DiscreteProbabilityDistribution;
+use crate::probability::induction::model_selection::ModelSelectionResult;
+use crate::probability::information::discrete_entropy;
+use crate::probability::information_unit::InformationUnit;
+use crate::probability::mixture_distribution::MixtureDistribution;
+use rand::RngCore;
+
+pub struct ModelAverage {
+    names: Vec<String>,
+    mixture: MixtureDistribution,
+}
+
+impl ModelAverage {
+    pub fn new(
+        names: Vec<String>,
+        models: Vec<Box<dyn ContinuousProbabilityDistribution>>,
+        weights: Vec<f64>,
+    ) -> Self {
+        //! Creates the Bayesian model average of fitted models.
+        //!
+        //! ## Arguments:
+        //! * `names`: `Vec<String>`, name of every model
+        //! * `models`: `Vec<Box<dyn ContinuousProbabilityDistribution>>`, fitted models
+        //! * `weights`: `Vec<f64>`, posterior probability of every model
+        //!
+        //! ## Returns:
+        //! * `ModelAverage`
+        //!
+        //! ## Panics:
+        //! * if the lengths differ or `weights` is not a probability vector
+        Self::try_new(names, models, weights).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(
+        names: Vec<String>,
+        models: Vec<Box<dyn ContinuousProbabilityDistribution>>,
+        weights: Vec<f64>,
+    ) -> Result<Self, KoError> {
+        //! Creates the Bayesian model average of fitted models, the mixture of the models
+        //! weighted by their posterior probabilities.
+        //!
+        //! ## Arguments:
+        //! * `names`: `Vec<String>`, name of every model
+        //! * `models`: `Vec<Box<dyn ContinuousProbabilityDistribution>>`, fitted models
+        //! * `weights`: `Vec<f64>`, posterior probability of every model
+        //!
+        //! ## Returns:
+        //! * `Result<ModelAverage, KoError>`
+        if names.len() != models.len() {
+            return Err(KoError::LengthMismatch(names.len(), models.len()));
+        }
+        Ok(Self {
+            names,
+            mixture: MixtureDistribution::try_new(models, weights)?,
+        })
+    }
+
+    pub fn from_selection(result: ModelSelectionResult) -> Self {
+        //! Averages the fitted candidates of a model selection, weighted by their decision
+        //! probabilities. Selecting by an information criterion yields Akaike or Schwarz weights,
+        //! which approximate the posterior probabilities of the candidates.
+        //!
+        //! ## Arguments:
+        //! * `result`: `ModelSelectionResult`, fitted candidates with decision probabilities
+        //!
+        //! ## Returns:
+        //! * `ModelAverage`
+        let weights: Vec<f64> = result.decision_probabilities();
+        let (names, models): (Vec<String>, Vec<Box<dyn ContinuousProbabilityDistribution>>) =
+            result
+                .fits
+                .into_iter()
+                .map(|fit| (fit.name, fit.distribution))
+                .unzip();
+        Self::new(names, models, weights)
+    }
+
+    pub fn names(&self) -> &[String] {
+        //! ## Returns:
+        //! * `&[String]`, name of every model
+        &self.names
+    }
+
+    pub fn models(&self) -> &[Box<dyn ContinuousProbabilityDistribution>] {
+        //! ## Returns:
+        //! * `&[Box<dyn ContinuousProbabilityDistribution>]`, the averaged models
+        self.mixture.components()
+    }
+
+    pub fn weights(&self) -> &DiscreteProbabilityDistribution<i32> {
+        //! ## Returns:
+        //! * `&DiscreteProbabilityDistribution<i32>`, posterior probability of every model, indexed
+        //!   by the position of the model
+        self.mixture.weights()
+    }
+
+    pub fn mixture(&self) -> &MixtureDistribution {
+        //! ## Returns:
+        //! * `&MixtureDistribution`, the models mixed by their posterior probabilities
+        &self.mixture
+    }
+
+    pub fn decision_entropy(&self) -> InformationUnit {
+        //! ## Returns:
+        //! * `InformationUnit`, entropy of the posterior model probabilities
+        discrete_entropy(self.mixture.weights())
+    }
+}
+
+impl ContinuousProbabilityDistribution for ModelAverage {
+    fn domain(&self) -> (f64, f64) {
+        //! ## Returns
+        //! * `domain`: `(f64, f64)`, union of the domains of the models
+        self.mixture.domain()
+    }
+
+    fn range(&self) -> (f64, f64) {
+        //! ## Returns
+        //! * `range`: `(f64, f64)`, bounds of the pdf
+        self.mixture.range()
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        //! Computes the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the pdf
+        //!
+        //! ## Returns:
+        //! * pdf(`x`): `f64`, the posterior-weighted pdf of the models
+        self.mixture.pdf(x)
+    }
+
+    fn log_pdf(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-pdf
+        //!
+        //! ## Returns:
+        //! * ln(pdf(`x`)): `f64`
+        self.mixture.log_pdf(x)
+    }
+
+    fn measure(&self, domain: &(f64, f64)) -> f64 {
+        //! Computes the measure of the interval `domain`.
+        //!
+        //! ## Arguments:
+        //! * `domain`: `&(f64, f64)`, interval at which to evaluate the measure
+        //!
+        //! ## Returns:
+        //! * measure of the pdf over `domain`: `f64`
+        self.mixture.measure(domain)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        //! Computes the cumulative density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the cdf
+        //!
+        //! ## Returns:
+        //! * cdf(`x`): `f64`
+        self.mixture.cdf(x)
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        //! Computes the survival function (complementary cdf) of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the survival function
+        //!
+        //! ## Returns:
+        //! * 1 - cdf(`x`): `f64`
+        self.mixture.survival(x)
+    }
+
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! Draws a model by its posterior probability, then a sample from that model.
+        //!
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, random number generator to draw from
+        //!
+        //! ## Returns:
+        //! * `f64`, a random sample
+        self.mixture.sample_with(rng)
+    }
+
+    fn raw_moment(&self, n: usize) -> Option<f64> {
+        //! Computes the raw moment E[X^n].
+        //!
+        //! ## Arguments:
+        //! * `n`: `usize`, order of the moment
+        //!
+        //! ## Returns:
+        //! * `Option<f64>`, the weighted moments of the models, `None` if one of them diverges
+        self.mixture.raw_moment(n)
+    }
+}
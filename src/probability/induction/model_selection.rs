@@ -0,0 +1,368 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, ExponentialDistribution, LogNormalDistribution,
+    NormalDistribution,
+};
+use crate::probability::discrete_distribution::DiscreteProbabilityDistribution;
+use crate::probability::induction::decision_entropy::{DecisionScoring, ScoreMap};
+use crate::probability::induction::goodness_of_fit::GoodnessOfFitMetric;
+use crate::probability::induction::information_criterion::{
+    information_criterion_weights, InformationCriterion,
+};
+use crate::probability::induction::power_law_fitting::try_fit_power_law_above;
+use crate::probability::information::discrete_entropy;
+use crate::probability::information_unit::InformationUnit;
+
+type Estimator = Box<dyn Fn(&[f64]) -> Result<Box<dyn ContinuousProbabilityDistribution>, KoError>>;
+
+pub struct CandidateModel {
+    name: String,
+    num_parameters: usize,
+    estimator: Estimator,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionCriterion {
+    GoodnessOfFit(GoodnessOfFitMetric),
+    InformationCriterion(InformationCriterion),
+}
+
+impl CandidateModel {
+    pub fn new<D, F>(name: &str, num_parameters: usize, estimator: F) -> Self
+    where
+        D: ContinuousProbabilityDistribution + 'static,
+        F: Fn(&[f64]) -> Result<D, KoError> + 'static,
+    {
+        //! Creates a candidate family from an estimator fitting one of its members to samples.
+        //!
+        //! ## Arguments:
+        //! * `name`: `&str`, name of the family
+        //! * `num_parameters`: `usize`, number of parameters the estimator fits
+        //! * `estimator`: `F`, fits a distribution of the family to samples
+        //!
+        //! ## Returns:
+        //! * `CandidateModel`
+        Self {
+            name: name.to_string(),
+            num_parameters,
+            estimator: Box::new(move |samples: &[f64]| {
+                estimator(samples)
+                    .map(|dist| Box::new(dist) as Box<dyn ContinuousProbabilityDistribution>)
+            }),
+        }
+    }
+
+    pub fn normal() -> Self {
+        //! ## Returns:
+        //! * `CandidateModel`, normal distributions fitted by maximum likelihood
+        Self::new("normal", 2, |samples: &[f64]| {
+            NormalDistribution::try_estimate(samples)
+        })
+    }
+
+    pub fn power_law() -> Self {
+        //! ## Returns:
+        //! * `CandidateModel`, unshifted power law distributions fitted by maximum likelihood, with
+        //!   x_min at the sample minimum and the exponent fitted above it
+        Self::new("power law", 2, |samples: &[f64]| {
+            let x_min: f64 = samples.iter().copied().fold(f64::INFINITY, f64::min);
+            try_fit_power_law_above(samples, x_min)
+        })
+    }
+
+    pub fn exponential() -> Self {
+        //! ## Returns:
+        //! * `CandidateModel`, exponential distributions located at the sample minimum
+        Self::new("exponential", 2, |samples: &[f64]| {
+            ExponentialDistribution::try_estimate(samples)
+        })
+    }
+
+    pub fn log_normal() -> Self {
+        //! ## Returns:
+        //! * `CandidateModel`, log-normal distributions fitted by maximum likelihood
+        Self::new("log-normal", 2, |samples: &[f64]| {
+            LogNormalDistribution::try_estimate(samples)
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        //! ## Returns:
+        //! * `&str`, name of the family
+        &self.name
+    }
+
+    pub fn num_parameters(&self) -> usize {
+        //! ## Returns:
+        //! * `usize`, number of parameters the estimator fits
+        self.num_parameters
+    }
+
+    pub fn fit(
+        &self,
+        samples: &[f64],
+    ) -> Result<Box<dyn ContinuousProbabilityDistribution>, KoError> {
+        //! Fits a member of the family to `samples`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples to fit
+        //!
+        //! ## Returns:
+        //! * `Result<Box<dyn ContinuousProbabilityDistribution>, KoError>`, the fitted distribution
+        (self.estimator)(samples)
+    }
+}
+
+pub struct ModelFit {
+    pub name: String,
+    pub distribution: Box<dyn ContinuousProbabilityDistribution>,
+    pub num_parameters: usize,
+    pub log_likelihood: f64,
+    pub score: f64,
+}
+
+pub struct ModelFailure {
+    pub name: String,
+    pub error: KoError,
+}
+
+pub struct ModelSelectionResult {
+    pub fits: Vec<ModelFit>,
+    pub failures: Vec<ModelFailure>,
+    pub decision_distribution: DiscreteProbabilityDistribution<i32>,
+    pub decision_entropy: InformationUnit,
+    pub winner: usize,
+}
+
+impl ModelSelectionResult {
+    pub fn winning_fit(&self) -> &ModelFit {
+        //! ## Returns:
+        //! * `&ModelFit`, the fit with the largest decision probability
+        &self.fits[self.winner]
+    }
+
+    pub fn decision_probabilities(&self) -> Vec<f64> {
+        //! ## Returns:
+        //! * `Vec<f64>`, decision probability of every fitted candidate in registration order
+        self.decision_distribution.probabilities()
+    }
+}
+
+pub struct ModelSelection {
+    candidates: Vec<CandidateModel>,
+    criterion: SelectionCriterion,
+    scoring: Option<DecisionScoring>,
+}
+
+impl Default for ModelSelection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModelSelection {
+    pub fn new() -> Self {
+        //! Creates a model selection without candidates, scoring fits by their
+        //! Kolmogorov-Smirnov distance.
+        //!
+        //! ## Returns:
+        //! * `ModelSelection`
+        Self {
+            candidates: Vec::new(),
+            criterion: SelectionCriterion::GoodnessOfFit(GoodnessOfFitMetric::KolmogorovSmirnov),
+            scoring: None,
+        }
+    }
+
+    pub fn with_metric(mut self, metric: GoodnessOfFitMetric) -> Self {
+        //! ## Arguments:
+        //! * `metric`: `GoodnessOfFitMetric`, statistic used to score every fit
+        //!
+        //! ## Returns:
+        //! * `ModelSelection`, `self` scoring fits by `metric`
+        self.criterion = SelectionCriterion::GoodnessOfFit(metric);
+        self
+    }
+
+    pub fn with_information_criterion(mut self, criterion: InformationCriterion) -> Self {
+        //! ## Arguments:
+        //! * `criterion`: `InformationCriterion`, criterion used to score every fit, whose
+        //!   Akaike or Schwarz weights become the decision probabilities
+        //!
+        //! ## Returns:
+        //! * `ModelSelection`, `self` scoring fits by `criterion`
+        self.criterion = SelectionCriterion::InformationCriterion(criterion);
+        self
+    }
+
+    pub fn with_scoring(mut self, scoring: DecisionScoring) -> Self {
+        //! Sets the scoring of a goodness-of-fit criterion, `DecisionScoring::default()` unless
+        //! given. With `ScoreMap::Likelihood` the log-likelihoods of the fits are scored instead of
+        //! their goodness-of-fit statistics. Information criteria are always weighted by their
+        //! Akaike or Schwarz weights, so selecting with a scoring and an information criterion
+        //! fails.
+        //!
+        //! ## Arguments:
+        //! * `scoring`: `DecisionScoring`, turns goodness-of-fit scores into decision
+        //!   probabilities
+        //!
+        //! ## Returns:
+        //! * `ModelSelection`, `self` deciding by `scoring`
+        self.scoring = Some(scoring);
+        self
+    }
+
+    pub fn criterion(&self) -> SelectionCriterion {
+        //! ## Returns:
+        //! * `SelectionCriterion`, criterion used to score every fit
+        self.criterion
+    }
+
+    pub fn with_candidate(mut self, candidate: CandidateModel) -> Self {
+        //! ## Arguments:
+        //! * `candidate`: `CandidateModel`, family to register
+        //!
+        //! ## Returns:
+        //! * `ModelSelection`, `self` with `candidate` registered
+        self.register(candidate);
+        self
+    }
+
+    pub fn register(&mut self, candidate: CandidateModel) {
+        //! Registers a candidate family.
+        //!
+        //! ## Arguments:
+        //! * `candidate`: `CandidateModel`, family to register
+        self.candidates.push(candidate);
+    }
+
+    pub fn candidates(&self) -> &[CandidateModel] {
+        //! ## Returns:
+        //! * `&[CandidateModel]`, the registered families in registration order
+        &self.candidates
+    }
+
+    pub fn select(&self, samples: &[f64]) -> ModelSelectionResult {
+        //! Fits every candidate to `samples` and decides between them.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples to fit
+        //!
+        //! ## Returns:
+        //! * `ModelSelectionResult`
+        //!
+        //! ## Panics:
+        //! * if no candidate is registered or no candidate can be fitted
+        self.try_select(samples)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_select(&self, samples: &[f64]) -> Result<ModelSelectionResult, KoError> {
+        //! Fits every candidate to `samples`, scores each fit by the criterion and turns the
+        //! scores into decision probabilities, whose entropy quantifies the uncertainty of the
+        //! decision. Candidates that cannot be fitted or scored are reported as failures and take
+        //! no part in the decision.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples to fit
+        //!
+        //! ## Returns:
+        //! * `Result<ModelSelectionResult, KoError>`, an error if no candidate is registered, none
+        //!   can be fitted or a scoring is combined with an information criterion
+        if self.candidates.is_empty() {
+            return Err(KoError::InvalidParameter(
+                "at least one candidate model is required".to_string(),
+            ));
+        }
+        if let (SelectionCriterion::InformationCriterion(_), Some(_)) =
+            (self.criterion, self.scoring)
+        {
+            return Err(KoError::InvalidParameter(
+                "information criteria are weighted by their own weights and take no scoring"
+                    .to_string(),
+            ));
+        }
+        if samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
+        let mut fits: Vec<ModelFit> = Vec::with_capacity(self.candidates.len());
+        let mut failures: Vec<ModelFailure> = Vec::new();
+        for candidate in self.candidates.iter() {
+            match self.fit_candidate(candidate, samples) {
+                Ok(fit) => fits.push(fit),
+                Err(error) => failures.push(ModelFailure {
+                    name: candidate.name.clone(),
+                    error,
+                }),
+            }
+        }
+        if fits.is_empty() {
+            return Err(failures.remove(0).error);
+        }
+        let scores: Vec<f64> = fits.iter().map(|fit| fit.score).collect();
+        let decision_probabilities: Vec<f64> = match self.criterion {
+            SelectionCriterion::GoodnessOfFit(_) => {
+                let scoring: DecisionScoring = self.scoring.unwrap_or_default();
+                if scoring.map == ScoreMap::Likelihood {
+                    let log_likelihoods: Vec<f64> =
+                        fits.iter().map(|fit| fit.log_likelihood).collect();
+                    scoring.decision_probabilities_from_log_likelihoods(&log_likelihoods)
+                } else {
+                    scoring.decision_probabilities(&scores)
+                }
+            }
+            SelectionCriterion::InformationCriterion(_) => information_criterion_weights(&scores),
+        };
+        let decision_distribution: DiscreteProbabilityDistribution<i32> =
+            DiscreteProbabilityDistribution::multinomial(decision_probabilities);
+        let decision_entropy: InformationUnit = discrete_entropy(&decision_distribution);
+        let winner: usize = decision_distribution
+            .probabilities
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(idx, _)| idx)
+            .unwrap();
+        Ok(ModelSelectionResult {
+            fits,
+            failures,
+            decision_distribution,
+            decision_entropy,
+            winner,
+        })
+    }
+
+    fn fit_candidate(
+        &self,
+        candidate: &CandidateModel,
+        samples: &[f64],
+    ) -> Result<ModelFit, KoError> {
+        //! Fits `candidate` to `samples` and scores the fit by the criterion.
+        //!
+        //! ## Returns:
+        //! * `Result<ModelFit, KoError>`, an error if the fit fails or its score is NaN
+        let distribution: Box<dyn ContinuousProbabilityDistribution> = candidate.fit(samples)?;
+        let log_likelihood: f64 = distribution.log_likelihood(samples);
+        let score: f64 = match self.criterion {
+            SelectionCriterion::GoodnessOfFit(metric) => {
+                metric.estimation_error(&*distribution, samples)
+            }
+            SelectionCriterion::InformationCriterion(criterion) => {
+                criterion.compute(log_likelihood, candidate.num_parameters, samples.len())
+            }
+        };
+        if score.is_nan() {
+            return Err(KoError::InvalidParameter(format!(
+                "the fit of {} has an undefined score",
+                candidate.name
+            )));
+        }
+        Ok(ModelFit {
+            name: candidate.name.clone(),
+            distribution,
+            num_parameters: candidate.num_parameters,
+            log_likelihood,
+            score,
+        })
+    }
+}
@@ -0,0 +1,217 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, PowerLawDistribution,
+};
+use crate::probability::induction::continuous_testing::ks_distance;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const MIN_TAIL_SAMPLES: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct PowerLawFit {
+    pub distribution: PowerLawDistribution,
+    pub x_min: f64,
+    pub num_tail_samples: usize,
+    pub ks_statistic: f64,
+}
+
+pub fn fit_power_law_above(samples: &[f64], x_min: f64) -> PowerLawDistribution {
+    //! Fits the exponent of a power law to the samples at or above `x_min` by maximum likelihood.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, samples whose tail is to be fitted
+    //! * `x_min`: `f64`, lower bound of the power law
+    //!
+    //! ## Returns:
+    //! * `PowerLawDistribution` supported on `[x_min, inf)`
+    //!
+    //! ## Panics:
+    //! * if `x_min` is not positive, a sample is NaN or the samples above `x_min` have no spread
+    try_fit_power_law_above(samples, x_min).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_fit_power_law_above(
+    samples: &[f64],
+    x_min: f64,
+) -> Result<PowerLawDistribution, KoError> {
+    //! Fits the exponent of a power law to the samples at or above `x_min` by maximum likelihood,
+    //! alpha = 1 + n / sum_i ln(x_i / x_min), which assumes the power law is unshifted.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, samples whose tail is to be fitted
+    //! * `x_min`: `f64`, lower bound of the power law
+    //!
+    //! ## Returns:
+    //! * `Result<PowerLawDistribution, KoError>` supported on `[x_min, inf)`
+    if x_min <= 0. || !x_min.is_finite() {
+        return Err(KoError::InvalidParameter(format!(
+            "x_min must be positive and finite, got {}",
+            x_min
+        )));
+    }
+    if samples.iter().any(|x| x.is_nan()) {
+        return Err(KoError::InvalidParameter(
+            "samples must not be NaN".to_string(),
+        ));
+    }
+    let tail: Vec<f64> = samples.iter().copied().filter(|&x| x >= x_min).collect();
+    if tail.is_empty() {
+        return Err(KoError::EmptySamples);
+    }
+    let log_sum: f64 = tail.iter().map(|x| (x / x_min).ln()).sum::<f64>();
+    if log_sum <= 0. {
+        return Err(KoError::InvalidParameter(
+            "samples have no spread to fit an exponent".to_string(),
+        ));
+    }
+    PowerLawDistribution::try_new(0., 1. + tail.len() as f64 / log_sum, x_min)
+}
+
+pub fn fit_power_law_tail(samples: &[f64]) -> PowerLawFit {
+    //! Fits a power law to the tail of `samples`, choosing x_min by the method of Clauset, Shalizi
+    //! and Newman.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, samples whose tail is to be fitted
+    //!
+    //! ## Returns:
+    //! * `PowerLawFit`, the fitted distribution together with x_min, the tail size and the
+    //!   Kolmogorov-Smirnov distance of the fit
+    //!
+    //! ## Panics:
+    //! * if fewer than `MIN_TAIL_SAMPLES` positive samples are given
+    try_fit_power_law_tail(samples).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_fit_power_law_tail(samples: &[f64]) -> Result<PowerLawFit, KoError> {
+    //! Fits a power law to the tail of `samples`. Every distinct sample value leaving at least
+    //! `MIN_TAIL_SAMPLES` samples in the tail is tried as x_min, the exponent above it is fitted by
+    //! maximum likelihood and the x_min minimising the Kolmogorov-Smirnov distance between the
+    //! fitted power law and the tail samples is chosen.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, samples whose tail is to be fitted
+    //!
+    //! ## Returns:
+    //! * `Result<PowerLawFit, KoError>`, the fitted distribution together with x_min, the tail
+    //!   size and the Kolmogorov-Smirnov distance of the fit
+    let mut sorted: Vec<f64> = samples.iter().copied().filter(|&x| x > 0.).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    if sorted.len() < MIN_TAIL_SAMPLES {
+        return Err(KoError::InvalidParameter(format!(
+            "at least {} positive samples are required",
+            MIN_TAIL_SAMPLES
+        )));
+    }
+
+    let mut best_fit: Option<PowerLawFit> = None;
+    let mut start: usize = 0;
+    while sorted.len() - start >= MIN_TAIL_SAMPLES {
+        let x_min: f64 = sorted[start];
+        let tail: Vec<f64> = sorted[start..].to_vec();
+        // tails without spread have no maximum likelihood exponent and are skipped
+        if let Ok(distribution) = try_fit_power_law_above(&tail, x_min) {
+            let ks_statistic: f64 = ks_distance(&distribution, &tail);
+            let improves: bool = match &best_fit {
+                Some(fit) => ks_statistic < fit.ks_statistic,
+                None => true,
+            };
+            if improves {
+                best_fit = Some(PowerLawFit {
+                    distribution,
+                    x_min,
+                    num_tail_samples: tail.len(),
+                    ks_statistic,
+                });
+            }
+        }
+        // advance to the next distinct value
+        while start < sorted.len() && sorted[start] == x_min {
+            start += 1;
+        }
+    }
+    best_fit.ok_or(KoError::InvalidParameter(
+        "samples have no spread to fit an exponent".to_string(),
+    ))
+}
+
+#[derive(Debug, Clone)]
+pub struct PowerLawBootstrap {
+    pub fit: PowerLawFit,
+    pub p_value: f64,
+    pub num_replicates: usize,
+}
+
+pub fn power_law_bootstrap(samples: &[f64], num_replicates: usize, seed: u64) -> PowerLawBootstrap {
+    //! Tests the hypothesis that the tail of `samples` follows a power law with the
+    //! semi-parametric bootstrap of Clauset, Shalizi and Newman.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, samples whose tail is to be tested
+    //! * `num_replicates`: `usize`, number of synthetic datasets
+    //! * `seed`: `u64`, seed of the random number generator generating the synthetic datasets
+    //!
+    //! ## Returns:
+    //! * `PowerLawBootstrap`, the fit to `samples` together with the p-value of the test
+    //!
+    //! ## Panics:
+    //! * if no power law can be fitted to `samples`
+    try_power_law_bootstrap(samples, num_replicates, seed).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_power_law_bootstrap(
+    samples: &[f64],
+    num_replicates: usize,
+    seed: u64,
+) -> Result<PowerLawBootstrap, KoError> {
+    //! Tests the hypothesis that the tail of `samples` follows a power law. Every synthetic dataset
+    //! draws each sample from the fitted power law with the empirical tail probability and
+    //! uniformly from the samples below x_min otherwise. The p-value is the fraction of synthetic
+    //! datasets whose refitted Kolmogorov-Smirnov distance exceeds the empirical one.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, samples whose tail is to be tested
+    //! * `num_replicates`: `usize`, number of synthetic datasets
+    //! * `seed`: `u64`, seed of the random number generator generating the synthetic datasets
+    //!
+    //! ## Returns:
+    //! * `Result<PowerLawBootstrap, KoError>`, the fit to `samples` together with the p-value of
+    //!   the test
+    if num_replicates == 0 {
+        return Err(KoError::InvalidParameter(
+            "num_replicates must be positive".to_string(),
+        ));
+    }
+    let fit: PowerLawFit = try_fit_power_law_tail(samples)?;
+    let body: Vec<f64> = samples
+        .iter()
+        .copied()
+        .filter(|&x| x > 0. && x < fit.x_min)
+        .collect();
+    let num_samples: usize = body.len() + fit.num_tail_samples;
+    let tail_probability: f64 = fit.num_tail_samples as f64 / num_samples as f64;
+
+    let mut rng: StdRng = StdRng::seed_from_u64(seed);
+    let mut num_exceeding: usize = 0;
+    for _ in 0..num_replicates {
+        let synthetic: Vec<f64> = (0..num_samples)
+            .map(|_| {
+                if body.is_empty() || rng.gen::<f64>() < tail_probability {
+                    fit.distribution.sample_with(&mut rng)
+                } else {
+                    body[rng.gen_range(0..body.len())]
+                }
+            })
+            .collect();
+        let synthetic_fit: PowerLawFit = try_fit_power_law_tail(&synthetic)?;
+        if synthetic_fit.ks_statistic > fit.ks_statistic {
+            num_exceeding += 1;
+        }
+    }
+    Ok(PowerLawBootstrap {
+        fit,
+        p_value: num_exceeding as f64 / num_replicates as f64,
+        num_replicates,
+    })
+}
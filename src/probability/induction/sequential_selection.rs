@@ -0,0 +1,188 @@
+use crate::error::KoError;
+use crate::probability::induction::model_selection::{ModelSelection, ModelSelectionResult};
+
+pub const DEFAULT_MIN_SAMPLES: usize = 10;
+
+pub struct SequentialSelection {
+    selection: ModelSelection,
+    entropy_threshold: f64,
+    patience: usize,
+    max_samples: usize,
+    min_samples: usize,
+}
+
+pub struct SequentialSelectionResult {
+    pub selection_result: ModelSelectionResult,
+    pub stopping_time: usize,
+    pub converged: bool,
+    pub entropy_trajectory: Vec<(f64, f64)>,
+}
+
+impl SequentialSelectionResult {
+    pub fn chosen_model(&self) -> &str {
+        //! ## Returns:
+        //! * `&str`, name of the model chosen when stopping
+        &self.selection_result.winning_fit().name
+    }
+}
+
+impl SequentialSelection {
+    pub fn new(
+        selection: ModelSelection,
+        entropy_threshold: f64,
+        patience: usize,
+        max_samples: usize,
+    ) -> Self {
+        //! Creates a sequential model selection which refits all candidates of `selection` after
+        //! every new sample and stops once the decision entropy stayed below `entropy_threshold`
+        //! for `patience` consecutive steps, or after `max_samples` samples.
+        //!
+        //! ## Arguments:
+        //! * `selection`: `ModelSelection`, candidates and scoring to decide between them
+        //! * `entropy_threshold`: `f64`, decision entropy in bits below which the decision is
+        //!   considered certain
+        //! * `patience`: `usize`, number of consecutive certain steps required to stop
+        //! * `max_samples`: `usize`, sample budget
+        //!
+        //! ## Returns:
+        //! * `SequentialSelection`
+        //!
+        //! ## Panics:
+        //! * if `patience` is zero or `max_samples` is smaller than `DEFAULT_MIN_SAMPLES`
+        Self::try_new(selection, entropy_threshold, patience, max_samples)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(
+        selection: ModelSelection,
+        entropy_threshold: f64,
+        patience: usize,
+        max_samples: usize,
+    ) -> Result<Self, KoError> {
+        //! Creates a sequential model selection which refits all candidates of `selection` after
+        //! every new sample and stops once the decision entropy stayed below `entropy_threshold`
+        //! for `patience` consecutive steps, or after `max_samples` samples.
+        //!
+        //! ## Arguments:
+        //! * `selection`: `ModelSelection`, candidates and scoring to decide between them
+        //! * `entropy_threshold`: `f64`, decision entropy in bits below which the decision is
+        //!   considered certain
+        //! * `patience`: `usize`, number of consecutive certain steps required to stop
+        //! * `max_samples`: `usize`, sample budget
+        //!
+        //! ## Returns:
+        //! * `Result<SequentialSelection, KoError>`
+        if patience == 0 {
+            return Err(KoError::InvalidParameter(
+                "patience must be positive".to_string(),
+            ));
+        }
+        Self {
+            selection,
+            entropy_threshold,
+            patience,
+            max_samples,
+            min_samples: DEFAULT_MIN_SAMPLES,
+        }
+        .validated()
+    }
+
+    pub fn with_min_samples(mut self, min_samples: usize) -> Self {
+        //! ## Arguments:
+        //! * `min_samples`: `usize`, number of samples collected before the first fit
+        //!
+        //! ## Returns:
+        //! * `SequentialSelection`, `self` fitting from `min_samples` samples on
+        //!
+        //! ## Panics:
+        //! * if `min_samples` is zero or exceeds the sample budget
+        self.min_samples = min_samples;
+        self.validated().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn validated(self) -> Result<Self, KoError> {
+        //! ## Returns:
+        //! * `Result<SequentialSelection, KoError>`, `self` if the sample budget admits a fit
+        if self.min_samples == 0 || self.max_samples < self.min_samples {
+            return Err(KoError::InvalidParameter(format!(
+                "the sample budget {} must be at least the minimum of {} samples",
+                self.max_samples, self.min_samples
+            )));
+        }
+        Ok(self)
+    }
+
+    pub fn run(&self, samples: impl IntoIterator<Item = f64>) -> SequentialSelectionResult {
+        //! Consumes `samples` until the stopping rule is met.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `impl IntoIterator<Item = f64>`, stream of samples
+        //!
+        //! ## Returns:
+        //! * `SequentialSelectionResult`
+        //!
+        //! ## Panics:
+        //! * if the stream ends before the first fit or a candidate cannot be fitted
+        self.try_run(samples)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_run(
+        &self,
+        samples: impl IntoIterator<Item = f64>,
+    ) -> Result<SequentialSelectionResult, KoError> {
+        //! Consumes `samples` one at a time. Once `min_samples` samples are collected, every
+        //! candidate is refitted after each new sample and the decision entropy is recorded. The
+        //! run stops when the entropy stayed below the threshold for `patience` consecutive steps,
+        //! when the sample budget is exhausted or when the stream ends.
+        //!
+        //! Candidates are refitted from scratch on all samples collected so far rather than
+        //! updated incrementally. A `CandidateModel` is an opaque estimator over the whole sample,
+        //! some of which (e.g. the truncated log-normal) have no sufficient statistics to update,
+        //! and every goodness-of-fit statistic or log-likelihood has to revisit all samples after
+        //! a refit anyway. A run over n samples therefore costs O(n^2) sample evaluations.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `impl IntoIterator<Item = f64>`, stream of samples
+        //!
+        //! ## Returns:
+        //! * `Result<SequentialSelectionResult, KoError>`, the final decision, the number of
+        //!   samples consumed, whether the entropy criterion was met and the entropy trajectory
+        //!   as (number of samples, decision entropy) pairs
+        let mut collected: Vec<f64> = Vec::new();
+        let mut entropy_trajectory: Vec<(f64, f64)> = Vec::new();
+        let mut last_result: Option<ModelSelectionResult> = None;
+        let mut certain_steps: usize = 0;
+        for sample in samples.into_iter().take(self.max_samples) {
+            collected.push(sample);
+            if collected.len() < self.min_samples {
+                continue;
+            }
+            let result: ModelSelectionResult = self.selection.try_select(&collected)?;
+            let entropy: f64 = result.decision_entropy.to_float();
+            entropy_trajectory.push((collected.len() as f64, entropy));
+            last_result = Some(result);
+            certain_steps = if entropy < self.entropy_threshold {
+                certain_steps + 1
+            } else {
+                0
+            };
+            if certain_steps >= self.patience {
+                break;
+            }
+        }
+        match last_result {
+            Some(selection_result) => Ok(SequentialSelectionResult {
+                selection_result,
+                stopping_time: collected.len(),
+                converged: certain_steps >= self.patience,
+                entropy_trajectory,
+            }),
+            None => Err(KoError::InvalidParameter(format!(
+                "the sample stream ended after {} of {} samples required for a fit",
+                collected.len(),
+                self.min_samples
+            ))),
+        }
+    }
+}
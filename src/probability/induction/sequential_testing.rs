@@ -0,0 +1,242 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::ContinuousProbabilityDistribution;
+
+const NUM_INTEGRATION_POINTS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SprtDecision {
+    AcceptH0,
+    AcceptH1,
+    Continue,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SprtExpectedSampleSize {
+    pub under_h0: f64,
+    pub under_h1: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SequentialProbabilityRatioTest<H0, H1> {
+    h0: H0,
+    h1: H1,
+    alpha: f64,
+    beta: f64,
+    log_likelihood_ratio: f64,
+    num_samples: usize,
+    decision: SprtDecision,
+}
+
+impl<H0, H1> SequentialProbabilityRatioTest<H0, H1>
+where
+    H0: ContinuousProbabilityDistribution,
+    H1: ContinuousProbabilityDistribution,
+{
+    pub fn new(h0: H0, h1: H1, alpha: f64, beta: f64) -> Self {
+        //! Creates Wald's sequential probability ratio test between two simple hypotheses.
+        //!
+        //! ## Arguments:
+        //! * `h0`: `H0`, distribution under the null hypothesis
+        //! * `h1`: `H1`, distribution under the alternative hypothesis
+        //! * `alpha`: `f64`, target probability of accepting H1 when H0 holds
+        //! * `beta`: `f64`, target probability of accepting H0 when H1 holds
+        //!
+        //! ## Returns:
+        //! * `SequentialProbabilityRatioTest<H0, H1>`
+        //!
+        //! ## Panics:
+        //! * if `alpha` or `beta` is not in (0, 1) or `alpha + beta` >= 1
+        Self::try_new(h0, h1, alpha, beta).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(h0: H0, h1: H1, alpha: f64, beta: f64) -> Result<Self, KoError> {
+        //! Creates Wald's sequential probability ratio test between two simple hypotheses.
+        //!
+        //! ## Arguments:
+        //! * `h0`: `H0`, distribution under the null hypothesis
+        //! * `h1`: `H1`, distribution under the alternative hypothesis
+        //! * `alpha`: `f64`, target probability of accepting H1 when H0 holds
+        //! * `beta`: `f64`, target probability of accepting H0 when H1 holds
+        //!
+        //! ## Returns:
+        //! * `Result<SequentialProbabilityRatioTest<H0, H1>, KoError>`
+        if !(alpha > 0. && alpha < 1. && beta > 0. && beta < 1.) {
+            return Err(KoError::InvalidParameter(
+                "alpha and beta must lie in (0, 1)".to_string(),
+            ));
+        }
+        if alpha + beta >= 1. {
+            return Err(KoError::InvalidParameter(
+                "alpha + beta must be smaller than 1".to_string(),
+            ));
+        }
+        Ok(Self {
+            h0,
+            h1,
+            alpha,
+            beta,
+            log_likelihood_ratio: 0.,
+            num_samples: 0,
+            decision: SprtDecision::Continue,
+        })
+    }
+
+    pub fn lower_threshold(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, ln(β / (1 - α)), below which H0 is accepted
+        (self.beta / (1. - self.alpha)).ln()
+    }
+
+    pub fn upper_threshold(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, ln((1 - β) / α), above which H1 is accepted
+        ((1. - self.beta) / self.alpha).ln()
+    }
+
+    pub fn log_likelihood_ratio(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, accumulated ln(L1 / L0) of the samples consumed so far
+        self.log_likelihood_ratio
+    }
+
+    pub fn num_samples(&self) -> usize {
+        //! ## Returns:
+        //! * `usize`, number of samples consumed so far
+        self.num_samples
+    }
+
+    pub fn decision(&self) -> SprtDecision {
+        //! ## Returns:
+        //! * `SprtDecision`, the current decision
+        self.decision
+    }
+
+    pub fn update(&mut self, x: f64) -> SprtDecision {
+        //! Consumes a sample and updates the decision. Samples arriving after a hypothesis was
+        //! accepted are ignored, as are samples whose likelihood ratio is undefined (NaN samples
+        //! or samples impossible under both hypotheses), which are not counted either.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, new sample
+        //!
+        //! ## Returns:
+        //! * `SprtDecision`, the decision after consuming `x`
+        if self.decision != SprtDecision::Continue {
+            return self.decision;
+        }
+        let increment: f64 = self.h1.log_pdf(x) - self.h0.log_pdf(x);
+        if increment.is_nan() {
+            return self.decision;
+        }
+        self.num_samples += 1;
+        self.log_likelihood_ratio += increment;
+        self.decision = if self.log_likelihood_ratio >= self.upper_threshold() {
+            SprtDecision::AcceptH1
+        } else if self.log_likelihood_ratio <= self.lower_threshold() {
+            SprtDecision::AcceptH0
+        } else {
+            SprtDecision::Continue
+        };
+        self.decision
+    }
+
+    pub fn run(&mut self, samples: impl IntoIterator<Item = f64>) -> SprtDecision {
+        //! Consumes samples until a hypothesis is accepted or the stream ends.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `impl IntoIterator<Item = f64>`, stream of samples
+        //!
+        //! ## Returns:
+        //! * `SprtDecision`, the decision after the last consumed sample
+        for x in samples {
+            if self.update(x) != SprtDecision::Continue {
+                break;
+            }
+        }
+        self.decision
+    }
+
+    pub fn reset(&mut self) {
+        //! Discards all consumed samples.
+        self.log_likelihood_ratio = 0.;
+        self.num_samples = 0;
+        self.decision = SprtDecision::Continue;
+    }
+
+    pub fn expected_sample_size(&self) -> Option<SprtExpectedSampleSize> {
+        //! Computes Wald's approximations of the expected number of samples until a decision,
+        //! E_0[N] = (α a + (1 - α) b) / E_0[z] and E_1[N] = ((1 - β) a + β b) / E_1[z], where a and
+        //! b are the upper and lower thresholds and z = ln(f1(x) / f0(x)). The expectations of z
+        //! are integrated numerically over the quantiles of either hypothesis.
+        //!
+        //! If the true hypothesis puts a probability q on outcomes the other one excludes, its drift
+        //! is infinite and the first such outcome ends the test, so 1 / q is reported instead, an
+        //! upper bound on the expected sample size.
+        //!
+        //! ## Returns:
+        //! * `Option<SprtExpectedSampleSize>`, the expected sample sizes under H0 and under H1,
+        //!   `None` if a drift is undefined or does not point towards the true hypothesis, as when
+        //!   the hypotheses coincide
+        let a: f64 = self.upper_threshold();
+        let b: f64 = self.lower_threshold();
+        let under_h0: f64 = expected_sample_size_under(
+            &log_likelihood_ratios(&self.h0, &self.h0, &self.h1),
+            self.alpha * a + (1. - self.alpha) * b,
+            f64::NEG_INFINITY,
+        )?;
+        let under_h1: f64 = expected_sample_size_under(
+            &log_likelihood_ratios(&self.h1, &self.h0, &self.h1),
+            (1. - self.beta) * a + self.beta * b,
+            f64::INFINITY,
+        )?;
+        Some(SprtExpectedSampleSize { under_h0, under_h1 })
+    }
+}
+
+fn log_likelihood_ratios(
+    truth: &impl ContinuousProbabilityDistribution,
+    h0: &impl ContinuousProbabilityDistribution,
+    h1: &impl ContinuousProbabilityDistribution,
+) -> Vec<f64> {
+    //! Evaluates ln(f1(x) / f0(x)) at the midpoints of `NUM_INTEGRATION_POINTS` equally likely
+    //! quantile intervals of `truth`
+    //!
+    //! ## Arguments:
+    //! * `truth`: `&impl ContinuousProbabilityDistribution`, distribution of x
+    //! * `h0`: `&impl ContinuousProbabilityDistribution`, null hypothesis
+    //! * `h1`: `&impl ContinuousProbabilityDistribution`, alternative hypothesis
+    //!
+    //! ## Returns:
+    //! * `Vec<f64>`, the log-likelihood ratios
+    (0..NUM_INTEGRATION_POINTS)
+        .map(|i| {
+            let x: f64 = truth.quantile((i as f64 + 0.5) / NUM_INTEGRATION_POINTS as f64);
+            h1.log_pdf(x) - h0.log_pdf(x)
+        })
+        .collect()
+}
+
+fn expected_sample_size_under(ratios: &[f64], numerator: f64, decisive: f64) -> Option<f64> {
+    //! Computes the expected sample size under the true hypothesis from its log-likelihood ratios
+    //!
+    //! ## Arguments:
+    //! * `ratios`: `&[f64]`, equally likely log-likelihood ratios under the true hypothesis
+    //! * `numerator`: `f64`, expected log-likelihood ratio at the end of the test
+    //! * `decisive`: `f64`, infinite ratio of an outcome the other hypothesis excludes, whose sign
+    //!   points towards the true hypothesis
+    //!
+    //! ## Returns:
+    //! * `Option<f64>`, the expected sample size, `None` if the drift is undefined or points away
+    //!   from the true hypothesis
+    let drift: f64 = ratios.iter().sum::<f64>() / ratios.len() as f64;
+    if drift == decisive {
+        let decisive_fraction: f64 =
+            ratios.iter().filter(|&&z| z == decisive).count() as f64 / ratios.len() as f64;
+        return Some(1. / decisive_fraction);
+    }
+    if !(drift.is_finite() && drift != 0. && drift.signum() == decisive.signum()) {
+        return None;
+    }
+    // at least one sample is consumed however far it overshoots the threshold
+    Some((numerator / drift).max(1.))
+}
@@ -0,0 +1,472 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::{
+    binomial_coefficient, standard_normal_cdf, ContinuousProbabilityDistribution,
+};
+use rand::distributions::Distribution;
+use rand::{Rng, RngCore};
+use statrs::distribution::Normal;
+use std::f64::consts::PI;
+
+const EPANECHNIKOV_SCALE: f64 = 2.214; // ratio of the canonical bandwidths to the gaussian kernel
+const LSCV_GRID_SIZE: usize = 40;
+const LSCV_REFINEMENT_ITERATIONS: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kernel {
+    Gaussian,
+    Epanechnikov,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BandwidthSelection {
+    Silverman,
+    Scott,
+    LeastSquaresCrossValidation,
+    Fixed(f64),
+}
+
+impl Kernel {
+    pub fn density(&self, u: f64) -> f64 {
+        //! ## Arguments:
+        //! * `u`: `f64`, standardised distance to a sample
+        //!
+        //! ## Returns:
+        //! * `f64`, the kernel K(`u`)
+        match self {
+            Kernel::Gaussian => (-0.5 * u.powi(2)).exp() / (2. * PI).sqrt(),
+            Kernel::Epanechnikov if u.abs() <= 1. => 0.75 * (1. - u.powi(2)),
+            Kernel::Epanechnikov => 0.,
+        }
+    }
+
+    pub fn cdf(&self, u: f64) -> f64 {
+        //! ## Arguments:
+        //! * `u`: `f64`, standardised distance to a sample
+        //!
+        //! ## Returns:
+        //! * `f64`, the integral of the kernel up to `u`
+        match self {
+            Kernel::Gaussian => standard_normal_cdf(u),
+            Kernel::Epanechnikov => {
+                let u: f64 = u.clamp(-1., 1.);
+                0.5 + 0.75 * u - 0.25 * u.powi(3)
+            }
+        }
+    }
+
+    fn convolution(&self, u: f64) -> f64 {
+        //! ## Arguments:
+        //! * `u`: `f64`, standardised distance between two samples
+        //!
+        //! ## Returns:
+        //! * `f64`, the self-convolution (K * K)(`u`)
+        match self {
+            Kernel::Gaussian => (-0.25 * u.powi(2)).exp() / (4. * PI).sqrt(),
+            Kernel::Epanechnikov if u.abs() <= 2. => {
+                3. / 160. * (2. - u.abs()).powi(3) * (u.powi(2) + 6. * u.abs() + 4.)
+            }
+            Kernel::Epanechnikov => 0.,
+        }
+    }
+
+    fn moment(&self, k: usize) -> f64 {
+        //! ## Arguments:
+        //! * `k`: `usize`, order of the moment
+        //!
+        //! ## Returns:
+        //! * `f64`, the moment E[U^`k`] of the kernel
+        if k % 2 == 1 {
+            return 0.;
+        }
+        match self {
+            // (k - 1)!!
+            Kernel::Gaussian => (1..k).step_by(2).map(|i| i as f64).product::<f64>(),
+            Kernel::Epanechnikov => 3. / ((k + 1) * (k + 3)) as f64,
+        }
+    }
+
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, random number generator to draw from
+        //!
+        //! ## Returns:
+        //! * `f64`, a random draw from the kernel
+        match self {
+            Kernel::Gaussian => Normal::new(0., 1.).unwrap().sample(rng),
+            Kernel::Epanechnikov => {
+                // the median of three uniforms in (-1, 1) is Epanechnikov distributed
+                let u: [f64; 3] = [
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                ];
+                if u[2].abs() >= u[1].abs() && u[2].abs() >= u[0].abs() {
+                    u[1]
+                } else {
+                    u[2]
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KernelDensityEstimate {
+    samples: Vec<f64>,
+    kernel: Kernel,
+    bandwidth: f64,
+}
+
+fn standard_deviation(samples: &[f64]) -> f64 {
+    //! ## Returns:
+    //! * `f64`, the sample standard deviation of `samples`
+    let n: f64 = samples.len() as f64;
+    let mean: f64 = samples.iter().sum::<f64>() / n;
+    (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.)).sqrt()
+}
+
+pub fn silverman_bandwidth(samples: &[f64], kernel: Kernel) -> f64 {
+    //! Computes Silverman's rule of thumb 0.9 min(σ, IQR / 1.34) n^(-1/5), scaled to `kernel`.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, at least two samples
+    //! * `kernel`: `Kernel`, kernel the bandwidth is used with
+    //!
+    //! ## Returns:
+    //! * `f64`, the bandwidth
+    let mut sorted: Vec<f64> = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    // linearly interpolated quartiles
+    let quartile = |p: f64| {
+        let position: f64 = p * (sorted.len() - 1) as f64;
+        let lower: usize = position.floor() as usize;
+        let upper: usize = position.ceil() as usize;
+        sorted[lower] + (position - lower as f64) * (sorted[upper] - sorted[lower])
+    };
+    let iqr: f64 = quartile(0.75) - quartile(0.25);
+    let sigma: f64 = standard_deviation(samples);
+    // fall back to the standard deviation if more than half of the samples coincide
+    let spread: f64 = if iqr > 0. {
+        sigma.min(iqr / 1.34)
+    } else {
+        sigma
+    };
+    kernel_scale(kernel) * 0.9 * spread * (samples.len() as f64).powf(-0.2)
+}
+
+pub fn scott_bandwidth(samples: &[f64], kernel: Kernel) -> f64 {
+    //! Computes Scott's rule of thumb 1.06 σ n^(-1/5), scaled to `kernel`.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, at least two samples
+    //! * `kernel`: `Kernel`, kernel the bandwidth is used with
+    //!
+    //! ## Returns:
+    //! * `f64`, the bandwidth
+    kernel_scale(kernel) * 1.06 * standard_deviation(samples) * (samples.len() as f64).powf(-0.2)
+}
+
+fn kernel_scale(kernel: Kernel) -> f64 {
+    //! ## Returns:
+    //! * `f64`, factor converting a bandwidth for the gaussian kernel to `kernel`
+    match kernel {
+        Kernel::Gaussian => 1.,
+        Kernel::Epanechnikov => EPANECHNIKOV_SCALE,
+    }
+}
+
+fn least_squares_cross_validation_score(samples: &[f64], kernel: Kernel, bandwidth: f64) -> f64 {
+    //! Computes the least-squares cross-validation score
+    //! int f^2 - 2 / n sum_i f_{-i}(x_i), which estimates the integrated squared error up to a
+    //! constant
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, samples of the estimate
+    //! * `kernel`: `Kernel`, kernel of the estimate
+    //! * `bandwidth`: `f64`, bandwidth to score
+    //!
+    //! ## Returns:
+    //! * `f64`, the score
+    let n: f64 = samples.len() as f64;
+    let mut convolution_sum: f64 = 0.;
+    let mut leave_one_out_sum: f64 = 0.;
+    for (i, x_i) in samples.iter().enumerate() {
+        for x_j in samples[i + 1..].iter() {
+            let u: f64 = (x_i - x_j) / bandwidth;
+            convolution_sum += 2. * kernel.convolution(u);
+            leave_one_out_sum += 2. * kernel.density(u);
+        }
+    }
+    convolution_sum += n * kernel.convolution(0.);
+    convolution_sum / (n.powi(2) * bandwidth) - 2. * leave_one_out_sum / (n * (n - 1.) * bandwidth)
+}
+
+pub fn least_squares_cross_validation_bandwidth(samples: &[f64], kernel: Kernel) -> f64 {
+    //! Selects the bandwidth minimising the least-squares cross-validation score. A logarithmic
+    //! grid between 1/20 and 3 times Silverman's bandwidth is searched first and the best cell is
+    //! refined by golden-section search.
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, at least two samples
+    //! * `kernel`: `Kernel`, kernel the bandwidth is used with
+    //!
+    //! ## Returns:
+    //! * `f64`, the bandwidth, or Silverman's bandwidth if that is not positive and finite
+    let reference: f64 = silverman_bandwidth(samples, kernel);
+    // a degenerate reference leaves no grid to search, e.g. for constant samples
+    if reference <= 0. || !reference.is_finite() {
+        return reference;
+    }
+    let score = |log_h: f64| least_squares_cross_validation_score(samples, kernel, log_h.exp());
+    let lower: f64 = (reference / 20.).ln();
+    let upper: f64 = (reference * 3.).ln();
+    let step: f64 = (upper - lower) / (LSCV_GRID_SIZE - 1) as f64;
+    let best: usize = (0..LSCV_GRID_SIZE)
+        .map(|i| (i, score(lower + i as f64 * step)))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .unwrap()
+        .0;
+    // golden-section search within the neighbouring grid cells
+    let ratio: f64 = (5f64.sqrt() - 1.) / 2.;
+    let mut a: f64 = lower + best.saturating_sub(1) as f64 * step;
+    let mut b: f64 = lower + (best + 1).min(LSCV_GRID_SIZE - 1) as f64 * step;
+    let mut c: f64 = b - ratio * (b - a);
+    let mut d: f64 = a + ratio * (b - a);
+    let mut score_c: f64 = score(c);
+    let mut score_d: f64 = score(d);
+    // each step keeps one interior point, so only one new score is evaluated
+    for _ in 0..LSCV_REFINEMENT_ITERATIONS {
+        if score_c < score_d {
+            b = d;
+            d = c;
+            score_d = score_c;
+            c = b - ratio * (b - a);
+            score_c = score(c);
+        } else {
+            a = c;
+            c = d;
+            score_c = score_d;
+            d = a + ratio * (b - a);
+            score_d = score(d);
+        }
+    }
+    ((a + b) / 2.).exp()
+}
+
+impl KernelDensityEstimate {
+    pub fn new(samples: &[f64], kernel: Kernel, bandwidth: BandwidthSelection) -> Self {
+        //! Creates a kernel density estimate from `samples`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples to smooth
+        //! * `kernel`: `Kernel`, kernel placed on every sample
+        //! * `bandwidth`: `BandwidthSelection`, rule choosing the bandwidth
+        //!
+        //! ## Returns:
+        //! * `KernelDensityEstimate`
+        //!
+        //! ## Panics:
+        //! * if fewer than two samples are given, a sample is not finite or the bandwidth is not
+        //!   positive
+        Self::try_new(samples, kernel, bandwidth).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(
+        samples: &[f64],
+        kernel: Kernel,
+        bandwidth: BandwidthSelection,
+    ) -> Result<Self, KoError> {
+        //! Creates a kernel density estimate from `samples`.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples to smooth
+        //! * `kernel`: `Kernel`, kernel placed on every sample
+        //! * `bandwidth`: `BandwidthSelection`, rule choosing the bandwidth
+        //!
+        //! ## Returns:
+        //! * `Result<KernelDensityEstimate, KoError>`
+        if samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
+        if samples.len() < 2 {
+            return Err(KoError::InvalidParameter(
+                "at least two samples are required".to_string(),
+            ));
+        }
+        if !samples.iter().all(|x| x.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "samples must be finite".to_string(),
+            ));
+        }
+        let bandwidth: f64 = match bandwidth {
+            BandwidthSelection::Silverman => silverman_bandwidth(samples, kernel),
+            BandwidthSelection::Scott => scott_bandwidth(samples, kernel),
+            BandwidthSelection::LeastSquaresCrossValidation => {
+                least_squares_cross_validation_bandwidth(samples, kernel)
+            }
+            BandwidthSelection::Fixed(bandwidth) => bandwidth,
+        };
+        if bandwidth <= 0. || !bandwidth.is_finite() {
+            return Err(KoError::InvalidParameter(format!(
+                "bandwidth must be positive and finite, got {}",
+                bandwidth
+            )));
+        }
+        let mut samples: Vec<f64> = samples.to_vec();
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Ok(Self {
+            samples,
+            kernel,
+            bandwidth,
+        })
+    }
+
+    pub fn samples(&self) -> &[f64] {
+        //! ## Returns:
+        //! * `&[f64]`, the samples in ascending order
+        &self.samples
+    }
+
+    pub fn kernel(&self) -> Kernel {
+        //! ## Returns:
+        //! * `Kernel`, kernel placed on every sample
+        self.kernel
+    }
+
+    pub fn bandwidth(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, the bandwidth
+        self.bandwidth
+    }
+
+    fn support_samples(&self, x: f64) -> &[f64] {
+        //! ## Returns:
+        //! * `&[f64]`, the samples whose kernel is non-zero at `x`
+        match self.kernel {
+            Kernel::Gaussian => &self.samples,
+            Kernel::Epanechnikov => {
+                let start: usize = self.samples.partition_point(|&y| y < x - self.bandwidth);
+                let end: usize = self.samples.partition_point(|&y| y <= x + self.bandwidth);
+                &self.samples[start..end]
+            }
+        }
+    }
+}
+
+impl ContinuousProbabilityDistribution for KernelDensityEstimate {
+    fn domain(&self) -> (f64, f64) {
+        //! ## Returns
+        //! * `domain`: `(f64, f64)`, domain of the pdf
+        match self.kernel {
+            Kernel::Gaussian => (-f64::INFINITY, f64::INFINITY),
+            Kernel::Epanechnikov => (
+                self.samples[0] - self.bandwidth,
+                self.samples[self.samples.len() - 1] + self.bandwidth,
+            ),
+        }
+    }
+
+    fn range(&self) -> (f64, f64) {
+        //! ## Returns
+        //! * `range`: `(f64, f64)`, range of the pdf
+        (0., self.kernel.density(0.) / self.bandwidth)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        //! Computes the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the pdf
+        //!
+        //! ## Returns:
+        //! * pdf(`x`): `f64`, 1 / (n h) sum_i K((`x` - x_i) / h)
+        self.support_samples(x)
+            .iter()
+            .map(|x_i| self.kernel.density((x - x_i) / self.bandwidth))
+            .sum::<f64>()
+            / (self.samples.len() as f64 * self.bandwidth)
+    }
+
+    fn measure(&self, domain: &(f64, f64)) -> f64 {
+        //! Computes the measure of the interval `domain`.
+        //!
+        //! ## Arguments:
+        //! * `domain`: `&(f64, f64)`, interval at which to evaluate the measure
+        //!
+        //! ## Returns:
+        //! * measure of the pdf over `domain`: `f64`
+        assert!(domain.0 < domain.1);
+        self.cdf(domain.1) - self.cdf(domain.0)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        //! Computes the cumulative density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the cdf
+        //!
+        //! ## Returns:
+        //! * cdf(`x`): `f64`
+        let n: f64 = self.samples.len() as f64;
+        match self.kernel {
+            Kernel::Gaussian => {
+                self.samples
+                    .iter()
+                    .map(|x_i| self.kernel.cdf((x - x_i) / self.bandwidth))
+                    .sum::<f64>()
+                    / n
+            }
+            Kernel::Epanechnikov => {
+                // kernels entirely below x contribute one each
+                let below: usize = self.samples.partition_point(|&y| y < x - self.bandwidth);
+                let partial: f64 = self
+                    .support_samples(x)
+                    .iter()
+                    .map(|x_i| self.kernel.cdf((x - x_i) / self.bandwidth))
+                    .sum::<f64>();
+                (below as f64 + partial) / n
+            }
+        }
+    }
+
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! Draws a sample by the smoothed bootstrap: a uniformly chosen sample perturbed by a
+        //! draw from the scaled kernel.
+        //!
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, random number generator to draw from
+        //!
+        //! ## Returns:
+        //! * `f64`, a random sample
+        let idx: usize = rng.gen_range(0..self.samples.len());
+        self.samples[idx] + self.bandwidth * self.kernel.sample_with(rng)
+    }
+
+    fn raw_moment(&self, n: usize) -> Option<f64> {
+        //! Computes the raw moment E[(X_i + h U)^n] with X_i uniform over the samples and U
+        //! distributed as the kernel.
+        //!
+        //! ## Arguments:
+        //! * `n`: `usize`, order of the moment
+        //!
+        //! ## Returns:
+        //! * `Option<f64>`, the moment
+        let num_samples: f64 = self.samples.len() as f64;
+        Some(
+            (0..=n)
+                .map(|k| {
+                    let sample_moment: f64 = self
+                        .samples
+                        .iter()
+                        .map(|x| x.powi((n - k) as i32))
+                        .sum::<f64>()
+                        / num_samples;
+                    binomial_coefficient(n, k)
+                        * sample_moment
+                        * self.bandwidth.powi(k as i32)
+                        * self.kernel.moment(k)
+                })
+                .sum::<f64>(),
+        )
+    }
+}
@@ -0,0 +1,430 @@
+use crate::error::KoError;
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, NormalDistribution,
+};
+use crate::probability::discrete_distribution::DiscreteProbabilityDistribution;
+use rand::RngCore;
+use std::f64::consts::PI;
+
+const RELATIVE_VARIANCE_FLOOR: f64 = 1e-6;
+
+pub struct MixtureDistribution {
+    components: Vec<Box<dyn ContinuousProbabilityDistribution>>,
+    weights: DiscreteProbabilityDistribution<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GaussianMixtureFit {
+    pub components: Vec<NormalDistribution>,
+    pub weights: Vec<f64>,
+    pub log_likelihood_trace: Vec<f64>,
+    pub converged: bool,
+}
+
+impl GaussianMixtureFit {
+    pub fn log_likelihood(&self) -> f64 {
+        //! ## Returns:
+        //! * `f64`, log-likelihood of the fitted mixture
+        *self.log_likelihood_trace.last().unwrap()
+    }
+
+    pub fn mixture(&self) -> MixtureDistribution {
+        //! ## Returns:
+        //! * `MixtureDistribution`, the fitted mixture
+        MixtureDistribution::new(
+            self.components
+                .iter()
+                .map(|component| {
+                    Box::new(component.clone()) as Box<dyn ContinuousProbabilityDistribution>
+                })
+                .collect(),
+            self.weights.clone(),
+        )
+    }
+}
+
+fn log_sum_exp(terms: impl Iterator<Item = f64>) -> f64 {
+    //! Computes ln(sum exp(t)) without overflowing
+    //!
+    //! ## Arguments:
+    //! * `terms`: `impl Iterator<Item = f64>`, logarithms of the summands
+    //!
+    //! ## Returns:
+    //! * `f64`, logarithm of the sum
+    let terms: Vec<f64> = terms.collect();
+    let max: f64 = terms.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if !max.is_finite() {
+        return max;
+    }
+    max + terms.iter().map(|t| (t - max).exp()).sum::<f64>().ln()
+}
+
+impl MixtureDistribution {
+    pub fn new(
+        components: Vec<Box<dyn ContinuousProbabilityDistribution>>,
+        weights: Vec<f64>,
+    ) -> Self {
+        //! Creates a finite mixture of continuous distributions.
+        //!
+        //! ## Arguments:
+        //! * `components`: `Vec<Box<dyn ContinuousProbabilityDistribution>>`, mixture components
+        //! * `weights`: `Vec<f64>`, probability of every component
+        //!
+        //! ## Returns:
+        //! * `MixtureDistribution`
+        //!
+        //! ## Panics:
+        //! * if there are no components, the lengths differ or `weights` is not a probability
+        //!   vector
+        Self::try_new(components, weights).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(
+        components: Vec<Box<dyn ContinuousProbabilityDistribution>>,
+        weights: Vec<f64>,
+    ) -> Result<Self, KoError> {
+        //! Creates a finite mixture of continuous distributions.
+        //!
+        //! ## Arguments:
+        //! * `components`: `Vec<Box<dyn ContinuousProbabilityDistribution>>`, mixture components
+        //! * `weights`: `Vec<f64>`, probability of every component
+        //!
+        //! ## Returns:
+        //! * `Result<MixtureDistribution, KoError>`
+        if components.len() != weights.len() {
+            return Err(KoError::LengthMismatch(components.len(), weights.len()));
+        }
+        if components.is_empty() {
+            return Err(KoError::InvalidParameter(
+                "at least one component is required".to_string(),
+            ));
+        }
+        let outcomes: Vec<i32> = (0..weights.len() as i32).collect();
+        Ok(Self {
+            components,
+            weights: DiscreteProbabilityDistribution::try_new(outcomes, weights)?,
+        })
+    }
+
+    pub fn components(&self) -> &[Box<dyn ContinuousProbabilityDistribution>] {
+        //! ## Returns:
+        //! * `&[Box<dyn ContinuousProbabilityDistribution>]`, the mixture components
+        &self.components
+    }
+
+    pub fn weights(&self) -> &DiscreteProbabilityDistribution<i32> {
+        //! ## Returns:
+        //! * `&DiscreteProbabilityDistribution<i32>`, probability of every component, indexed by
+        //!   the position of the component
+        &self.weights
+    }
+
+    fn weighted_sum(&self, func: impl Fn(&dyn ContinuousProbabilityDistribution) -> f64) -> f64 {
+        //! ## Arguments:
+        //! * `func`: `impl Fn(&dyn ContinuousProbabilityDistribution) -> f64`, evaluated per
+        //!   component
+        //!
+        //! ## Returns:
+        //! * `f64`, sum of `func` over the components with positive weight, weighted by their
+        //!   weights
+        self.components
+            .iter()
+            .zip(self.weights.probabilities.iter())
+            .filter(|(_, &w)| w > 0.)
+            .map(|(component, &w)| w * func(component.as_ref()))
+            .sum::<f64>()
+    }
+
+    pub fn fit_gaussian_mixture(
+        samples: &[f64],
+        num_components: usize,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> GaussianMixtureFit {
+        //! Fits a mixture of normal distributions by expectation-maximisation.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples to fit
+        //! * `num_components`: `usize`, number of normal components
+        //! * `tolerance`: `f64`, the iteration stops once the log-likelihood improves by less
+        //! * `max_iterations`: `usize`, maximal number of iterations
+        //!
+        //! ## Returns:
+        //! * `GaussianMixtureFit`
+        //!
+        //! ## Panics:
+        //! * if a sample is not finite, there are fewer samples than components, `tolerance` is
+        //!   not positive or `max_iterations` is zero
+        Self::try_fit_gaussian_mixture(samples, num_components, tolerance, max_iterations)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_fit_gaussian_mixture(
+        samples: &[f64],
+        num_components: usize,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<GaussianMixtureFit, KoError> {
+        //! Fits a mixture of normal distributions by expectation-maximisation. The means are
+        //! initialised at evenly spaced sample quantiles, the variances at the sample variance and
+        //! the weights uniformly, so fits are deterministic. Variances are floored at a small
+        //! fraction of the sample variance to keep components from collapsing onto single
+        //! samples.
+        //!
+        //! ## Arguments:
+        //! * `samples`: `&[f64]`, samples to fit
+        //! * `num_components`: `usize`, number of normal components
+        //! * `tolerance`: `f64`, the iteration stops once the log-likelihood improves by less
+        //! * `max_iterations`: `usize`, maximal number of iterations
+        //!
+        //! ## Returns:
+        //! * `Result<GaussianMixtureFit, KoError>`, the fitted components and weights with the
+        //!   log-likelihood after every iteration, ending with that of the returned parameters
+        if samples.is_empty() {
+            return Err(KoError::EmptySamples);
+        }
+        if !samples.iter().all(|x| x.is_finite()) {
+            return Err(KoError::InvalidParameter(
+                "samples must be finite".to_string(),
+            ));
+        }
+        if num_components == 0 || samples.len() < num_components {
+            return Err(KoError::InvalidParameter(format!(
+                "the number of components must lie between 1 and the number of samples {}",
+                samples.len()
+            )));
+        }
+        if tolerance <= 0. || tolerance.is_nan() {
+            return Err(KoError::InvalidParameter(
+                "tolerance must be positive".to_string(),
+            ));
+        }
+        if max_iterations == 0 {
+            return Err(KoError::InvalidParameter(
+                "at least one iteration is required".to_string(),
+            ));
+        }
+        let n: f64 = samples.len() as f64;
+        let mean: f64 = samples.iter().sum::<f64>() / n;
+        let variance: f64 = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        if variance <= 0. || variance.is_nan() {
+            return Err(KoError::InvalidParameter(
+                "samples must not all be equal".to_string(),
+            ));
+        }
+        let variance_floor: f64 = RELATIVE_VARIANCE_FLOOR * variance;
+
+        let mut sorted: Vec<f64> = samples.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut means: Vec<f64> = (0..num_components)
+            .map(|j| sorted[((j as f64 + 0.5) / num_components as f64 * n) as usize])
+            .collect();
+        let mut variances: Vec<f64> = vec![variance; num_components];
+        let mut weights: Vec<f64> = vec![1. / num_components as f64; num_components];
+
+        let mut log_likelihood_trace: Vec<f64> = Vec::new();
+        let mut converged: bool = false;
+        let mut responsibilities: Vec<Vec<f64>> = vec![vec![0.; num_components]; samples.len()];
+        for _ in 0..max_iterations {
+            let log_likelihood: f64 =
+                expectation_step(samples, &weights, &means, &variances, &mut responsibilities);
+            let improvement: f64 = match log_likelihood_trace.last() {
+                Some(previous) => log_likelihood - previous,
+                None => f64::INFINITY,
+            };
+            log_likelihood_trace.push(log_likelihood);
+            if improvement.abs() < tolerance {
+                converged = true;
+                break;
+            }
+            // maximisation: weighted maximum likelihood estimates of every component
+            for j in 0..num_components {
+                let total: f64 = responsibilities.iter().map(|r| r[j]).sum::<f64>();
+                if total <= 0. {
+                    continue;
+                }
+                weights[j] = total / n;
+                means[j] = samples
+                    .iter()
+                    .zip(responsibilities.iter())
+                    .map(|(x, r)| r[j] * x)
+                    .sum::<f64>()
+                    / total;
+                variances[j] = (samples
+                    .iter()
+                    .zip(responsibilities.iter())
+                    .map(|(x, r)| r[j] * (x - means[j]).powi(2))
+                    .sum::<f64>()
+                    / total)
+                    .max(variance_floor);
+            }
+            let weight_sum: f64 = weights.iter().sum::<f64>();
+            weights.iter_mut().for_each(|w| *w /= weight_sum);
+        }
+        if !converged {
+            // the last maximisation moved the parameters, so the trace ends with their likelihood
+            log_likelihood_trace.push(expectation_step(
+                samples,
+                &weights,
+                &means,
+                &variances,
+                &mut responsibilities,
+            ));
+        }
+        Ok(GaussianMixtureFit {
+            components: means
+                .iter()
+                .zip(variances.iter())
+                .map(|(&mean, &variance)| NormalDistribution::new(mean, variance))
+                .collect(),
+            weights,
+            log_likelihood_trace,
+            converged,
+        })
+    }
+}
+
+fn expectation_step(
+    samples: &[f64],
+    weights: &[f64],
+    means: &[f64],
+    variances: &[f64],
+    responsibilities: &mut [Vec<f64>],
+) -> f64 {
+    //! Computes the posterior probability of every gaussian component given every sample
+    //!
+    //! ## Arguments:
+    //! * `samples`: `&[f64]`, samples the mixture is fitted to
+    //! * `weights`: `&[f64]`, weights of the components
+    //! * `means`: `&[f64]`, means of the components
+    //! * `variances`: `&[f64]`, variances of the components
+    //! * `responsibilities`: `&mut [Vec<f64>]`, overwritten with the posterior probabilities of
+    //!   the components for every sample
+    //!
+    //! ## Returns:
+    //! * `f64`, log-likelihood of the mixture
+    let mut log_likelihood: f64 = 0.;
+    for (x, responsibility) in samples.iter().zip(responsibilities.iter_mut()) {
+        let log_joint: Vec<f64> = (0..weights.len())
+            .map(|j| {
+                weights[j].ln()
+                    - 0.5 * (2. * PI * variances[j]).ln()
+                    - (x - means[j]).powi(2) / (2. * variances[j])
+            })
+            .collect();
+        let log_marginal: f64 = log_sum_exp(log_joint.iter().copied());
+        log_likelihood += log_marginal;
+        for (r, l) in responsibility.iter_mut().zip(log_joint.iter()) {
+            *r = (l - log_marginal).exp();
+        }
+    }
+    log_likelihood
+}
+
+impl ContinuousProbabilityDistribution for MixtureDistribution {
+    fn domain(&self) -> (f64, f64) {
+        //! ## Returns
+        //! * `domain`: `(f64, f64)`, union of the domains of the components
+        self.components
+            .iter()
+            .map(|component| component.domain())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |acc, d| {
+                (acc.0.min(d.0), acc.1.max(d.1))
+            })
+    }
+
+    fn range(&self) -> (f64, f64) {
+        //! ## Returns
+        //! * `range`: `(f64, f64)`, bounds of the pdf
+        (0., self.weighted_sum(|component| component.range().1))
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        //! Computes the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the pdf
+        //!
+        //! ## Returns:
+        //! * pdf(`x`): `f64`, the weighted pdf of the components
+        self.weighted_sum(|component| component.pdf(x))
+    }
+
+    fn log_pdf(&self, x: f64) -> f64 {
+        //! Computes the natural logarithm of the probability density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the log-pdf
+        //!
+        //! ## Returns:
+        //! * ln(pdf(`x`)): `f64`
+        log_sum_exp(
+            self.components
+                .iter()
+                .zip(self.weights.probabilities.iter())
+                .filter(|(_, &w)| w > 0.)
+                .map(|(component, &w)| w.ln() + component.log_pdf(x)),
+        )
+    }
+
+    fn measure(&self, domain: &(f64, f64)) -> f64 {
+        //! Computes the measure of the interval `domain`.
+        //!
+        //! ## Arguments:
+        //! * `domain`: `&(f64, f64)`, interval at which to evaluate the measure
+        //!
+        //! ## Returns:
+        //! * measure of the pdf over `domain`: `f64`
+        self.weighted_sum(|component| component.measure(domain))
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        //! Computes the cumulative density function of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the cdf
+        //!
+        //! ## Returns:
+        //! * cdf(`x`): `f64`
+        self.weighted_sum(|component| component.cdf(x))
+    }
+
+    fn survival(&self, x: f64) -> f64 {
+        //! Computes the survival function (complementary cdf) of the outcome `x`.
+        //!
+        //! ## Arguments:
+        //! * `x`: `f64`, outcome at which to evaluate the survival function
+        //!
+        //! ## Returns:
+        //! * 1 - cdf(`x`): `f64`
+        self.weighted_sum(|component| component.survival(x))
+    }
+
+    fn sample_with(&self, rng: &mut dyn RngCore) -> f64 {
+        //! Draws a component by its weight, then a sample from that component.
+        //!
+        //! ## Arguments:
+        //! * `rng`: `&mut dyn RngCore`, random number generator to draw from
+        //!
+        //! ## Returns:
+        //! * `f64`, a random sample
+        let idx: i32 = self.weights.sample_with(rng);
+        self.components[idx as usize].sample_with(rng)
+    }
+
+    fn raw_moment(&self, n: usize) -> Option<f64> {
+        //! Computes the raw moment E[X^n].
+        //!
+        //! ## Arguments:
+        //! * `n`: `usize`, order of the moment
+        //!
+        //! ## Returns:
+        //! * `Option<f64>`, the weighted moments of the components, `None` if one of them diverges
+        self.components
+            .iter()
+            .zip(self.weights.probabilities.iter())
+            .filter(|(_, &w)| w > 0.)
+            .map(|(component, &w)| component.raw_moment(n).map(|m| w * m))
+            .sum::<Option<f64>>()
+    }
+}
@@ -3,4 +3,6 @@ pub mod discrete_distribution;
 pub mod induction;
 pub mod information;
 pub mod information_unit;
+pub mod kernel_density;
+pub mod mixture_distribution;
 pub mod utils;
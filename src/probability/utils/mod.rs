@@ -1,3 +1,4 @@
 pub mod empirical_moment;
+pub mod optimize;
 pub mod sample;
 pub mod softmax;
@@ -0,0 +1,90 @@
+pub fn nelder_mead(
+    func: impl Fn(&[f64]) -> f64,
+    start: &[f64],
+    step: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Vec<f64> {
+    //! Minimises `func` with the Nelder-Mead downhill simplex method
+    //!
+    //! ## Arguments:
+    //! * `func`: `impl Fn(&[f64]) -> f64`, function to minimise
+    //! * `start`: `&[f64]`, initial guess
+    //! * `step`: `f64`, edge length of the initial simplex
+    //! * `tolerance`: `f64`, spread of function values across the simplex at which to stop
+    //! * `max_iterations`: `usize`, maximum number of iterations
+    //!
+    //! ## Returns:
+    //! * `Vec<f64>`, the best point found
+    let dim: usize = start.len();
+    // build initial simplex around start
+    let mut simplex: Vec<Vec<f64>> = vec![start.to_vec()];
+    for i in 0..dim {
+        let mut vertex: Vec<f64> = start.to_vec();
+        vertex[i] += step;
+        simplex.push(vertex);
+    }
+    let mut values: Vec<f64> = simplex.iter().map(|x| func(x)).collect();
+
+    for _ in 0..max_iterations {
+        // order vertices by function value
+        let mut order: Vec<usize> = (0..=dim).collect();
+        order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+        simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+        values = order.iter().map(|&i| values[i]).collect();
+        if (values[dim] - values[0]).abs() < tolerance {
+            break;
+        }
+        // centroid of all vertices except the worst
+        let centroid: Vec<f64> = (0..dim)
+            .map(|j| simplex[..dim].iter().map(|x| x[j]).sum::<f64>() / dim as f64)
+            .collect();
+        let towards = |coefficient: f64| -> Vec<f64> {
+            centroid
+                .iter()
+                .zip(simplex[dim].iter())
+                .map(|(c, w)| c + coefficient * (c - w))
+                .collect()
+        };
+        // reflect
+        let reflected: Vec<f64> = towards(1.);
+        let reflected_value: f64 = func(&reflected);
+        if reflected_value < values[0] {
+            // expand
+            let expanded: Vec<f64> = towards(2.);
+            let expanded_value: f64 = func(&expanded);
+            if expanded_value < reflected_value {
+                simplex[dim] = expanded;
+                values[dim] = expanded_value;
+            } else {
+                simplex[dim] = reflected;
+                values[dim] = reflected_value;
+            }
+        } else if reflected_value < values[dim - 1] {
+            simplex[dim] = reflected;
+            values[dim] = reflected_value;
+        } else {
+            // contract
+            let contracted: Vec<f64> = towards(-0.5);
+            let contracted_value: f64 = func(&contracted);
+            if contracted_value < values[dim] {
+                simplex[dim] = contracted;
+                values[dim] = contracted_value;
+            } else {
+                // shrink towards the best vertex
+                for i in 1..=dim {
+                    simplex[i] = simplex[0]
+                        .iter()
+                        .zip(simplex[i].iter())
+                        .map(|(b, x)| b + 0.5 * (x - b))
+                        .collect();
+                    values[i] = func(&simplex[i]);
+                }
+            }
+        }
+    }
+    let best: usize = (0..=dim)
+        .min_by(|&a, &b| values[a].total_cmp(&values[b]))
+        .unwrap();
+    simplex[best].clone()
+}
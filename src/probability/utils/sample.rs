@@ -1,5 +1,6 @@
 use crate::probability::continuous_distribution::ContinuousProbabilityDistribution;
 use crate::probability::discrete_distribution::DiscreteProbabilityDistribution;
+use rand::RngCore;
 
 pub fn discrete_sample(n: usize, dist: &DiscreteProbabilityDistribution<i32>) -> Vec<i32> {
     //! Samples `n` times from a `DiscreteProbabilityDistribution<i32>`
@@ -17,6 +18,28 @@ pub fn discrete_sample(n: usize, dist: &DiscreteProbabilityDistribution<i32>) ->
     samples
 }
 
+pub fn discrete_sample_with(
+    n: usize,
+    dist: &DiscreteProbabilityDistribution<i32>,
+    rng: &mut dyn RngCore,
+) -> Vec<i32> {
+    //! Samples `n` times from a `DiscreteProbabilityDistribution<i32>` using the random number
+    //! generator `rng`
+    //!
+    //! ## Arguments:
+    //! * `n`: `usize`, number of samples
+    //! * `dist`: `&DiscreteProbabilityDistribution<i32>`
+    //! * `rng`: `&mut dyn RngCore`, source of randomness
+    //!
+    //! ## Returns:
+    //! * `samples`: `Vec<i32>`
+    let mut samples: Vec<i32> = Vec::with_capacity(n);
+    for _ in 0..n {
+        samples.push(dist.sample_with(rng));
+    }
+    samples
+}
+
 pub fn continuous_sample(n: usize, dist: &impl ContinuousProbabilityDistribution) -> Vec<f64> {
     //! Samples `n` times from a `impl ContinuousProbabilityDistribution`
     //!
@@ -32,3 +55,25 @@ pub fn continuous_sample(n: usize, dist: &impl ContinuousProbabilityDistribution
     }
     samples
 }
+
+pub fn continuous_sample_with(
+    n: usize,
+    dist: &impl ContinuousProbabilityDistribution,
+    rng: &mut dyn RngCore,
+) -> Vec<f64> {
+    //! Samples `n` times from a `impl ContinuousProbabilityDistribution` using the random number
+    //! generator `rng`
+    //!
+    //! ## Arguments:
+    //! * `n`: `usize`, number of samples
+    //! * `dist`: `&impl ContinuousProbabilityDistribution`
+    //! * `rng`: `&mut dyn RngCore`, source of randomness
+    //!
+    //! ## Returns:
+    //! * `samples`: `Vec<f64>`
+    let mut samples: Vec<f64> = Vec::with_capacity(n);
+    for _ in 0..n {
+        samples.push(dist.sample_with(rng));
+    }
+    samples
+}
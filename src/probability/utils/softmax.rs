@@ -12,3 +12,24 @@ pub fn softmax(x: &Vec<f64>) -> Vec<f64> {
     let sum_exp_x: f64 = exp_x.iter().sum();
     exp_x.iter().map(|exp_x_i| exp_x_i / sum_exp_x).collect()
 }
+
+pub fn softmax_with_temperature(x: &[f64], inverse_temperature: f64) -> Vec<f64> {
+    //! Softmax function exp(β x_i) / sum_j exp(β x_j), shifted by the maximum to avoid overflow
+    //!
+    //! ## Arguments:
+    //! * `x`: `&[f64]` - Input vector
+    //! * `inverse_temperature`: `f64` - Inverse temperature β, larger values sharpen the output
+    //!
+    //! ## Returns:
+    //! * `Vec<f64>` - Softmax of input vector
+    let max: f64 = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if !max.is_finite() {
+        return vec![1. / x.len() as f64; x.len()];
+    }
+    let exp_x: Vec<f64> = x
+        .iter()
+        .map(|x_i| (inverse_temperature * (x_i - max)).exp())
+        .collect();
+    let sum_exp_x: f64 = exp_x.iter().sum();
+    exp_x.iter().map(|exp_x_i| exp_x_i / sum_exp_x).collect()
+}
@@ -1,6 +1,21 @@
 mod test_continuous_distribution;
+mod test_continuous_testing;
+mod test_decision_entropy;
 mod test_discrete_distribution;
+mod test_discrete_testing;
+mod test_empirical_cdf;
+mod test_goodness_of_fit;
 mod test_information;
+mod test_information_criterion;
 mod test_information_unit;
+mod test_kernel_density;
+mod test_likelihood_ratio;
+mod test_mixture_distribution;
+mod test_model_averaging;
+mod test_model_selection;
 mod test_moment;
+mod test_optimize;
+mod test_power_law_fitting;
+mod test_sequential_selection;
+mod test_sequential_testing;
 mod test_softmax;
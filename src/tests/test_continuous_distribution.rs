@@ -1,11 +1,20 @@
 #[cfg(test)]
+use crate::error::KoError;
+#[cfg(test)]
 use crate::probability::continuous_distribution::{
-    ContinuousProbabilityDistribution, NormalDistribution, PowerLawDistribution,
+    numerical_quantile, ContinuousProbabilityDistribution, ExponentialDistribution,
+    LogNormalDistribution, NormalDistribution, PowerLawDistribution,
 };
+#[cfg(test)]
+use crate::probability::induction::continuous_testing::ks_distance;
+#[cfg(test)]
+use crate::probability::utils::sample::{continuous_sample, continuous_sample_with};
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
 
 #[test]
 fn test_normal_cdf() {
-    let tolerance: f64 = 1e-2;
+    let tolerance: f64 = 1e-15;
 
     let normal_distribution: NormalDistribution = NormalDistribution::new(0., 1.);
     assert!((normal_distribution.cdf(0.) - 0.5).abs() < tolerance);
@@ -16,13 +25,37 @@ fn test_normal_cdf() {
 
     let normal_distribution: NormalDistribution = NormalDistribution::new(10., 23.);
     assert!((normal_distribution.cdf(10.) - 0.5).abs() < tolerance);
-    assert!((normal_distribution.measure(&(9., 15.)) - 0.434029613381541).abs() < tolerance);
+    assert!((normal_distribution.measure(&(9., 15.)) - 0.4340130702331452).abs() < tolerance);
 
     let normal_distribution: NormalDistribution = NormalDistribution::new(100., 100.);
     assert!((normal_distribution.cdf(100.) - 0.5).abs() < tolerance);
-    assert!((normal_distribution.measure(&(90., 110.)) - 0.68).abs() < tolerance);
-    assert!((normal_distribution.measure(&(80., 120.)) - 0.954).abs() < tolerance);
-    assert!((normal_distribution.measure(&(70., 130.)) - 0.997).abs() < tolerance);
+    assert!((normal_distribution.measure(&(90., 110.)) - 0.6826894921370859).abs() < tolerance);
+    assert!((normal_distribution.measure(&(80., 120.)) - 0.9544997361036416).abs() < tolerance);
+    assert!((normal_distribution.measure(&(70., 130.)) - 0.9973002039367398).abs() < tolerance);
+}
+
+#[test]
+fn test_normal_cdf_tails() {
+    // rounding z / sqrt(2) alone costs a relative error of about z^2 machine epsilons
+    let relative_tolerance: f64 = 1e-12;
+
+    let normal_distribution: NormalDistribution = NormalDistribution::new(0., 1.);
+    let tail_values: Vec<(f64, f64)> = vec![
+        (-5., 2.866515718791939e-7),
+        (-10., 7.619853024160526e-24),
+        (-30., 4.906713927148187e-198),
+        (-37.5, 4.605353009581955e-308),
+    ];
+    for (x, expected) in tail_values {
+        assert!((normal_distribution.cdf(x) - expected).abs() / expected < relative_tolerance);
+    }
+    assert_eq!(normal_distribution.cdf(-40.), 0.);
+    assert_eq!(normal_distribution.cdf(40.), 1.);
+    assert!(
+        (normal_distribution.measure(&(-31., -30.)) - 4.906713927148187e-198).abs()
+            / 4.906713927148187e-198
+            < relative_tolerance
+    );
 }
 
 #[test]
@@ -43,4 +76,329 @@ fn test_power_law_cdf() {
     let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2., 1.);
     assert!((power_law.cdf(10f64.powi(10)) - 1.).abs() < tolerance);
     assert!((power_law.measure(&(10., 100.)) - 0.09).abs() < tolerance);
+
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(1., 3., 2.);
+    assert!((power_law.cdf(10f64.powi(10)) - 1.).abs() < tolerance);
+    assert!((power_law.measure(&(2., 3.)) - 0.75).abs() < tolerance);
+}
+
+#[test]
+fn test_exponential_cdf() {
+    let tolerance: f64 = 1e-12;
+
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(2., 0.);
+    assert!((exponential.pdf(0.) - 2.).abs() < tolerance);
+    assert!((exponential.pdf(-1.) - 0.).abs() < tolerance);
+    assert!((exponential.cdf(1.) - 0.8646647167633873).abs() < tolerance);
+    assert!((exponential.measure(&(0.5, 1.)) - 0.2325441579348505).abs() < tolerance);
+
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(0.5, 3.);
+    assert!((exponential.cdf(3.) - 0.).abs() < tolerance);
+    assert!((exponential.cdf(5.) - 0.6321205588285577).abs() < tolerance);
+}
+
+#[test]
+fn test_exponential_estimate() {
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(1.5, 2.);
+    let samples: Vec<f64> = continuous_sample(10000, &exponential);
+    assert!(samples.iter().all(|&x| x >= 2.));
+    let estimate: ExponentialDistribution = ExponentialDistribution::estimate(&samples);
+    assert!((estimate.rate - 1.5).abs() < 0.1);
+    assert!((estimate.location - 2.).abs() < 1e-2);
+    assert!(ks_distance(&estimate, &samples) < 0.05);
+}
+
+#[test]
+fn test_power_law_estimate() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2.5, 10.);
+    let samples: Vec<f64> = continuous_sample_with(5000, &power_law, &mut rng);
+    let estimate: PowerLawDistribution = PowerLawDistribution::estimate(&samples);
+    assert_eq!(estimate.shift(), 0.);
+    assert!((estimate.exponent() - 2.5).abs() < 0.1);
+    assert!((estimate.min_x() - 10.).abs() < 1e-2);
+    assert!(ks_distance(&estimate, &samples) < 0.03);
+    assert!(matches!(
+        PowerLawDistribution::try_estimate(&[-1., 2.]),
+        Err(KoError::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn test_log_normal_cdf() {
+    let tolerance: f64 = 1e-15;
+
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new(0., 1.);
+    assert!((log_normal.pdf(1.) - 0.3989422804014327).abs() < tolerance);
+    assert!((log_normal.cdf(1.) - 0.5).abs() < tolerance);
+    assert!((log_normal.cdf(std::f64::consts::E) - 0.8413447460685429).abs() < tolerance);
+    assert!((log_normal.cdf(0.) - 0.).abs() < tolerance);
+
+    // truncating at the median doubles the density in the tail
+    let truncated: LogNormalDistribution = LogNormalDistribution::new_truncated(0., 1., 1.);
+    assert!((truncated.cdf(1.) - 0.).abs() < tolerance);
+    assert!((truncated.pdf(2.) - 2. * log_normal.pdf(2.)).abs() < tolerance);
+    assert!((truncated.cdf(std::f64::consts::E) - 0.6826894921370859).abs() < tolerance);
+}
+
+#[test]
+fn test_log_normal_estimate() {
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new(1., 0.5);
+    let samples: Vec<f64> = continuous_sample(10000, &log_normal);
+    let estimate: LogNormalDistribution = LogNormalDistribution::estimate(&samples);
+    assert!((estimate.mu() - 1.).abs() < 0.05);
+    assert!((estimate.sigma() - 0.5).abs() < 0.05);
+
+    let truncated: LogNormalDistribution = LogNormalDistribution::new_truncated(1., 0.5, 3.);
+    let samples: Vec<f64> = continuous_sample(10000, &truncated);
+    assert!(samples.iter().all(|&x| x >= 3.));
+    let estimate: LogNormalDistribution = LogNormalDistribution::estimate_truncated(&samples, 3.);
+    assert!((estimate.mu() - 1.).abs() < 0.1);
+    assert!((estimate.sigma() - 0.5).abs() < 0.1);
+    assert!(ks_distance(&estimate, &samples) < 0.05);
+}
+
+#[test]
+fn test_quantile() {
+    let tolerance: f64 = 1e-9;
+    let probabilities: Vec<f64> = vec![1e-6, 0.01, 0.25, 0.5, 0.75, 0.99, 1. - 1e-6];
+
+    let normal: NormalDistribution = NormalDistribution::new(3., 4.);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(1., 2.5, 2.);
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(0.5, 1.);
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new_truncated(0., 1., 1.5);
+    for &p in probabilities.iter() {
+        assert!((normal.cdf(normal.quantile(p)) - p).abs() < tolerance);
+        assert!((power_law.cdf(power_law.quantile(p)) - p).abs() < tolerance);
+        assert!((exponential.cdf(exponential.quantile(p)) - p).abs() < tolerance);
+        assert!((log_normal.cdf(log_normal.quantile(p)) - p).abs() < tolerance);
+        // closed forms agree with the numerical fallback
+        assert!((normal.quantile(p) - numerical_quantile(&normal, p)).abs() < tolerance);
+        assert!((power_law.quantile(p) - numerical_quantile(&power_law, p)).abs() < 1e-6);
+    }
+    assert!((normal.quantile(0.5) - 3.).abs() < tolerance);
+    assert!((normal.quantile(0.8413447460685429) - 5.).abs() < tolerance);
+    assert_eq!(normal.quantile(0.), f64::NEG_INFINITY);
+    assert_eq!(power_law.quantile(0.), 2.);
+    assert_eq!(power_law.quantile(1.), f64::INFINITY);
+}
+
+#[test]
+fn test_seeded_sampling() {
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2., 1.);
+    let samples_a: Vec<f64> = continuous_sample_with(100, &normal, &mut StdRng::seed_from_u64(7));
+    let samples_b: Vec<f64> = continuous_sample_with(100, &normal, &mut StdRng::seed_from_u64(7));
+    let samples_c: Vec<f64> = continuous_sample_with(100, &normal, &mut StdRng::seed_from_u64(8));
+    assert_eq!(samples_a, samples_b);
+    assert_ne!(samples_a, samples_c);
+
+    let mut rng_a: StdRng = StdRng::seed_from_u64(7);
+    let mut rng_b: StdRng = StdRng::seed_from_u64(7);
+    for _ in 0..100 {
+        assert_eq!(
+            power_law.sample_with(&mut rng_a),
+            power_law.sample_with(&mut rng_b)
+        );
+    }
+
+    // implementors without a direct sampler fall back to inverse transform sampling
+    let uniform: UnitUniform = UnitUniform;
+    let samples_a: Vec<f64> = continuous_sample_with(100, &uniform, &mut StdRng::seed_from_u64(7));
+    let samples_b: Vec<f64> = continuous_sample_with(100, &uniform, &mut StdRng::seed_from_u64(7));
+    assert_eq!(samples_a, samples_b);
+    assert!(samples_a.iter().all(|&x| x > 0. && x < 1.));
+}
+
+#[cfg(test)]
+struct UnitUniform;
+
+#[cfg(test)]
+impl ContinuousProbabilityDistribution for UnitUniform {
+    fn domain(&self) -> (f64, f64) {
+        (0., 1.)
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (1., 1.)
+    }
+
+    fn pdf(&self, _x: f64) -> f64 {
+        1.
+    }
+
+    fn measure(&self, domain: &(f64, f64)) -> f64 {
+        self.cdf(domain.1) - self.cdf(domain.0)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        x.clamp(0., 1.)
+    }
+
+    fn raw_moment(&self, n: usize) -> Option<f64> {
+        Some(1. / (n + 1) as f64)
+    }
+}
+
+#[test]
+fn test_try_new() {
+    assert!(matches!(
+        NormalDistribution::try_new(0., 0.),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        NormalDistribution::try_estimate(&[]),
+        Err(KoError::EmptySamples)
+    ));
+    assert!(matches!(
+        NormalDistribution::try_estimate(&[1., 1.]),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        PowerLawDistribution::try_new(0., 1., 1.),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        PowerLawDistribution::try_new(1., 2., 1.),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        PowerLawDistribution::try_estimate(&[]),
+        Err(KoError::EmptySamples)
+    ));
+    assert!(matches!(
+        PowerLawDistribution::try_estimate(&[1., f64::NAN, 3.]),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        ExponentialDistribution::try_new(-1., 0.),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        ExponentialDistribution::try_estimate_with_location(&[1., 2.], 3.),
+        Err(KoError::EmptySamples)
+    ));
+    assert!(matches!(
+        ExponentialDistribution::try_estimate(&[1., f64::NAN, 3.]),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        LogNormalDistribution::try_estimate(&[1., -2.]),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(NormalDistribution::try_estimate(&[1., 2.]).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "variance must be positive")]
+fn test_new_panics() {
+    NormalDistribution::new(0., -1.);
+}
+
+#[test]
+fn test_log_pdf() {
+    let tolerance: f64 = 1e-12;
+    let normal: NormalDistribution = NormalDistribution::new(1., 2.);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0.5, 2.5, 2.);
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(0.5, 1.);
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new_truncated(0., 1., 1.5);
+    for &x in [2., 3.5, 10.].iter() {
+        assert!((normal.log_pdf(x) - normal.pdf(x).ln()).abs() < tolerance);
+        assert!((power_law.log_pdf(x) - power_law.pdf(x).ln()).abs() < tolerance);
+        assert!((exponential.log_pdf(x) - exponential.pdf(x).ln()).abs() < tolerance);
+        assert!((log_normal.log_pdf(x) - log_normal.pdf(x).ln()).abs() < tolerance);
+    }
+    // the log-pdf stays finite where the pdf underflows
+    assert_eq!(normal.pdf(100.), 0.);
+    assert!(
+        (normal.log_pdf(100.) - (-0.5 * (4. * std::f64::consts::PI).ln() - 2450.25)).abs() < 1e-9
+    );
+    assert_eq!(power_law.log_pdf(1.), f64::NEG_INFINITY);
+    assert_eq!(exponential.log_pdf(0.), f64::NEG_INFINITY);
+
+    let samples: Vec<f64> = vec![2., 3.5, 10.];
+    let log_likelihood: f64 = samples.iter().map(|&x| normal.log_pdf(x)).sum::<f64>();
+    assert!((normal.log_likelihood(&samples) - log_likelihood).abs() < tolerance);
+}
+
+#[test]
+fn test_moments() {
+    let tolerance: f64 = 1e-10;
+
+    let normal: NormalDistribution = NormalDistribution::new(1., 4.);
+    assert!((normal.raw_moment(2).unwrap() - 5.).abs() < tolerance);
+    assert!((normal.raw_moment(3).unwrap() - 13.).abs() < tolerance);
+    assert!((normal.raw_moment(4).unwrap() - 73.).abs() < tolerance);
+    assert_eq!(normal.mean(), Some(1.));
+    assert_eq!(normal.kurtosis(), Some(3.));
+
+    // moments of order exponent - 1 and above diverge
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2., 1.);
+    assert!(power_law.mean().is_none());
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 4.5, 2.);
+    assert!((power_law.mean().unwrap() - 2.8).abs() < tolerance);
+    assert!((power_law.variance().unwrap() - 14. / 9.375).abs() < tolerance);
+    assert!(power_law.raw_moment(3).unwrap().is_finite());
+    assert!(power_law.raw_moment(4).is_none());
+    assert!(power_law.kurtosis().is_none());
+    // closed forms agree with the generic definitions through raw moments
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(1., 8., 3.);
+    let mean: f64 = power_law.raw_moment(1).unwrap();
+    let variance: f64 = power_law.raw_moment(2).unwrap() - mean.powi(2);
+    assert!((power_law.variance().unwrap() - variance).abs() < 1e-8);
+    let third_central: f64 = power_law.raw_moment(3).unwrap() - 3. * mean * variance - mean.powi(3);
+    assert!((power_law.skewness().unwrap() - third_central / variance.powf(1.5)).abs() < 1e-6);
+
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(2., 1.);
+    assert!((exponential.mean().unwrap() - 1.5).abs() < tolerance);
+    assert!((exponential.variance().unwrap() - 0.25).abs() < tolerance);
+    assert!((exponential.raw_moment(2).unwrap() - 2.5).abs() < tolerance);
+
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new(0., 0.5);
+    assert!((log_normal.mean().unwrap() - 0.125f64.exp()).abs() < tolerance);
+    // the truncated mean matches the integral of the quantile function
+    let truncated: LogNormalDistribution = LogNormalDistribution::new_truncated(0., 0.5, 1.2);
+    let num_points: usize = 100000;
+    let integral: f64 = (0..num_points)
+        .map(|i| truncated.quantile((i as f64 + 0.5) / num_points as f64))
+        .sum::<f64>()
+        / num_points as f64;
+    assert!((truncated.mean().unwrap() - integral).abs() < 1e-4);
+    assert!(truncated.skewness().unwrap() > 0.);
+}
+
+#[test]
+fn test_survival() {
+    let tolerance: f64 = 1e-12;
+
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    assert!((normal.survival(10.) - 7.619853024160526e-24).abs() / 7.619853024160526e-24 < 1e-11);
+    let log_survivals: Vec<(f64, f64)> = vec![
+        (10., -53.23128515051247),
+        (37., -689.0305855768906),
+        (38., -726.5572160188201),
+        (100., -5005.524208694205),
+    ];
+    for (x, expected) in log_survivals {
+        assert!((normal.log_survival(x) - expected).abs() / expected.abs() < 1e-11);
+    }
+    assert!((normal.hazard(40.) - 40.02496884720726).abs() < 1e-8);
+    assert!((normal.survival(-1.) - normal.cdf(1.)).abs() < tolerance);
+
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2.5, 1.);
+    assert!((power_law.survival(1e10) - 1e-15).abs() / 1e-15 < 1e-10);
+    assert!((power_law.log_survival(1e100) - (-150. * 10f64.ln())).abs() < 1e-9);
+    assert!((power_law.hazard(3.) - 0.5).abs() < tolerance);
+    assert_eq!(power_law.survival(0.5), 1.);
+
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(2., 1.);
+    assert!((exponential.log_survival(1001.) - (-2000.)).abs() < tolerance);
+    assert!((exponential.hazard(5.) - 2.).abs() < tolerance);
+
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new(0., 1.);
+    assert!((log_normal.log_survival(1e30) - (-2391.008527534262)).abs() < 1e-8);
+    for &x in [0.5, 1., 3.].iter() {
+        assert!((log_normal.survival(x) - (1. - log_normal.cdf(x))).abs() < tolerance);
+        assert!((log_normal.hazard(x) - log_normal.pdf(x) / log_normal.survival(x)).abs() < 1e-10);
+    }
 }
@@ -0,0 +1,80 @@
+#[cfg(test)]
+use crate::probability::continuous_distribution::{ExponentialDistribution, NormalDistribution};
+#[cfg(test)]
+use crate::probability::induction::continuous_testing::{
+    kolmogorov_p_value, kolmogorov_survival, ks_distance, ks_test, ks_two_sample_test,
+    try_ks_two_sample_test, KsTest,
+};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_ks_distance_exact() {
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(1., 0.);
+    let samples: Vec<f64> = vec![0.5, 1., 2.];
+    let expected: f64 = samples
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let y: f64 = 1. - (-x).exp();
+            f64::max((i + 1) as f64 / 3. - y, y - i as f64 / 3.)
+        })
+        .fold(0., f64::max);
+    assert!((ks_distance(&exponential, &samples) - expected).abs() < 1e-15);
+
+    // a single sample far outside the bulk is detected on both sides of its step
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    assert!((ks_distance(&normal, &vec![0.]) - 0.5).abs() < 1e-15);
+    assert!(ks_distance(&normal, &vec![10.]) > 1. - 1e-15);
+}
+
+#[test]
+fn test_kolmogorov_p_value() {
+    // tabulated two-sided critical values at the 5% and 1% levels
+    assert!((kolmogorov_p_value(0.40925, 10) - 0.05).abs() < 1e-4);
+    assert!((kolmogorov_p_value(0.48893, 10) - 0.01).abs() < 1e-4);
+    assert!((kolmogorov_p_value(0.29408, 20) - 0.05).abs() < 1e-4);
+    assert!((kolmogorov_survival(1.3581) - 0.05).abs() < 1e-4);
+    assert!((kolmogorov_survival(0.8276) - 0.5).abs() < 1e-4);
+    // D_1 = max(U, 1 - U)
+    assert!((kolmogorov_p_value(0.6, 1) - 0.8).abs() < 1e-12);
+    assert_eq!(kolmogorov_p_value(0., 5), 1.);
+    assert_eq!(kolmogorov_p_value(1., 5), 0.);
+}
+
+#[test]
+fn test_ks_test() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let samples: Vec<f64> = continuous_sample_with(200, &normal, &mut rng);
+    let test: KsTest = ks_test(&normal, &samples);
+    assert_eq!(test.statistic, ks_distance(&normal, &samples));
+    assert!(test.p_value > 0.01);
+
+    let shifted: NormalDistribution = NormalDistribution::new(1., 1.);
+    assert!(ks_test(&shifted, &samples).p_value < 1e-6);
+}
+
+#[test]
+fn test_ks_two_sample_test() {
+    // all of x lies below all of y
+    let disjoint: KsTest = ks_two_sample_test(&[1., 2., 3.], &[4., 5.]);
+    assert_eq!(disjoint.statistic, 1.);
+    // ties across both batches are compared after the common jump
+    let tied: KsTest = ks_two_sample_test(&[1., 2., 2., 3.], &[2., 2.]);
+    assert_eq!(tied.statistic, 0.25);
+    assert_eq!(ks_two_sample_test(&[1., 2.], &[2., 1.]).statistic, 0.);
+
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let samples_x: Vec<f64> = continuous_sample_with(300, &normal, &mut rng);
+    let samples_y: Vec<f64> = continuous_sample_with(200, &normal, &mut rng);
+    assert!(ks_two_sample_test(&samples_x, &samples_y).p_value > 0.01);
+
+    let shifted: NormalDistribution = NormalDistribution::new(0.5, 1.);
+    let samples_z: Vec<f64> = continuous_sample_with(200, &shifted, &mut rng);
+    assert!(ks_two_sample_test(&samples_x, &samples_z).p_value < 1e-3);
+    assert!(try_ks_two_sample_test(&samples_x, &[]).is_err());
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+use crate::probability::induction::decision_entropy::{
+    compute_decision_entropy, Decision, DecisionScoring, ScoreMap,
+};
+
+#[test]
+fn test_decision_scoring() {
+    let errors: Vec<f64> = vec![0.1, 0.2, 0.4];
+    // the default scoring reproduces `compute_decision_entropy`
+    let decision: Decision = DecisionScoring::default().decide(&errors);
+    assert!(
+        (decision.entropy.to_float() - compute_decision_entropy(&errors).to_float()).abs() < 1e-8
+    );
+
+    let negative: Vec<f64> =
+        DecisionScoring::new(ScoreMap::Negative, 10.).decision_probabilities(&errors);
+    let sum: f64 = 1. + (-1f64).exp() + (-3f64).exp();
+    assert!((negative[0] - 1. / sum).abs() < 1e-15);
+    assert!((negative[2] - (-3f64).exp() / sum).abs() < 1e-15);
+
+    // negative log scores with unit inverse temperature are proportional to 1 / error
+    let negative_log: Vec<f64> =
+        DecisionScoring::new(ScoreMap::NegativeLog, 1.).decision_probabilities(&errors);
+    assert!((negative_log[0] / negative_log[1] - 2.).abs() < 1e-8);
+
+    // likelihood scoring weights by likelihood, taking negative log-likelihoods as errors
+    let scoring: DecisionScoring = DecisionScoring::new(ScoreMap::Likelihood, 1.);
+    let likelihood: Vec<f64> =
+        scoring.decision_probabilities_from_log_likelihoods(&[-1000., -1000. + 3f64.ln()]);
+    assert!((likelihood[1] - 0.75).abs() < 1e-12);
+    let decision: Decision = scoring.decide(&[1000., 1000. - 3f64.ln()]);
+    assert!((decision.distribution.probabilities[1] - 0.75).abs() < 1e-12);
+    let decision: Decision = scoring.decide_from_log_likelihoods(&[-1000., -1000. + 3f64.ln()]);
+    assert!((decision.distribution.probabilities[1] - 0.75).abs() < 1e-12);
+}
+
+#[test]
+fn test_decision_temperature() {
+    let errors: Vec<f64> = vec![0.1, 0.2];
+    let cold: Decision =
+        DecisionScoring::with_temperature(ScoreMap::Negative, 0.01).decide(&errors);
+    let hot: Decision = DecisionScoring::with_temperature(ScoreMap::Negative, 100.).decide(&errors);
+    assert!(cold.entropy.to_float() < hot.entropy.to_float());
+    assert!(hot.entropy.to_float() < 1.);
+    assert_eq!(cold.distribution.probabilities.len(), 2);
+    assert!(DecisionScoring::try_new(ScoreMap::Negative, 0.).is_err());
+    assert!(DecisionScoring::try_new(ScoreMap::Negative, f64::INFINITY).is_err());
+}
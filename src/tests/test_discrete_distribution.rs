@@ -1,7 +1,13 @@
 #[cfg(test)]
+use crate::error::KoError;
+#[cfg(test)]
 use crate::probability::discrete_distribution::{
     discrete_convolution, DiscreteProbabilityDistribution,
 };
+#[cfg(test)]
+use crate::probability::utils::sample::discrete_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
 
 #[test]
 #[should_panic]
@@ -85,3 +91,34 @@ fn test_distributions() {
         DiscreteProbabilityDistribution::convoluted_binomial(100, 0.5);
     assert!((0.5f64.powi(100) - conv.probabilities()[0]).abs() < tolerance);
 }
+
+#[test]
+fn test_seeded_sampling() {
+    let dist: DiscreteProbabilityDistribution<i32> =
+        DiscreteProbabilityDistribution::convoluted_binomial(10, 0.3);
+    let samples_a: Vec<i32> = dist.sample_n_with(100, &mut StdRng::seed_from_u64(7));
+    let samples_b: Vec<i32> = discrete_sample_with(100, &dist, &mut StdRng::seed_from_u64(7));
+    assert_eq!(samples_a, samples_b);
+    assert!(samples_a.iter().all(|x| (0..=10).contains(x)));
+}
+
+#[test]
+fn test_try_new() {
+    assert!(matches!(
+        DiscreteProbabilityDistribution::try_new(vec![1, 2, 3], vec![0.5, 0.25, 0.125, 0.125]),
+        Err(KoError::LengthMismatch(3, 4))
+    ));
+    assert!(matches!(
+        DiscreteProbabilityDistribution::try_new(vec![1, 2, 3], vec![0.5, 0.25, 0.125]),
+        Err(KoError::NonNormalisedProbabilities(_))
+    ));
+    assert!(matches!(
+        DiscreteProbabilityDistribution::try_new(vec![1, 2], vec![1.5, -0.5]),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        DiscreteProbabilityDistribution::try_estimate(&vec![]),
+        Err(KoError::EmptySamples)
+    ));
+    assert!(DiscreteProbabilityDistribution::try_new(vec![1, 2], vec![0.5, 0.5]).is_ok());
+}
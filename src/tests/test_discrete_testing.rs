@@ -0,0 +1,56 @@
+#[cfg(test)]
+use crate::probability::discrete_distribution::DiscreteProbabilityDistribution;
+#[cfg(test)]
+use crate::probability::induction::discrete_testing::{
+    chi_square_test, g_test, try_chi_square_test, ChiSquareTest,
+};
+
+#[cfg(test)]
+fn die_samples() -> Vec<i32> {
+    [5, 8, 9, 8, 10, 20]
+        .iter()
+        .enumerate()
+        .flat_map(|(outcome, &count)| vec![outcome as i32; count])
+        .collect()
+}
+
+#[test]
+fn test_chi_square_test() {
+    let die: DiscreteProbabilityDistribution<i32> =
+        DiscreteProbabilityDistribution::multinomial(vec![1. / 6.; 6]);
+    let test: ChiSquareTest = chi_square_test(&die, &die_samples());
+    assert!((test.statistic - 13.4).abs() < 1e-10);
+    assert_eq!(test.degrees_of_freedom, 5);
+    assert!((test.p_value - 0.0199052203347744).abs() < 1e-10);
+}
+
+#[test]
+fn test_g_test() {
+    let die: DiscreteProbabilityDistribution<i32> =
+        DiscreteProbabilityDistribution::multinomial(vec![1. / 6.; 6]);
+    let test: ChiSquareTest = g_test(&die, &die_samples());
+    assert!((test.statistic - 11.757332492902774).abs() < 1e-10);
+    assert_eq!(test.degrees_of_freedom, 5);
+    assert!((test.p_value - 0.0382685065829748).abs() < 1e-10);
+}
+
+#[test]
+fn test_chi_square_pooling() {
+    // expected counts 2, 48, 48, 2 pool into the bins {0, 1} and {2, 3}
+    let dist: DiscreteProbabilityDistribution<i32> =
+        DiscreteProbabilityDistribution::multinomial(vec![0.02, 0.48, 0.48, 0.02]);
+    let samples: Vec<i32> = [vec![0; 4], vec![1; 46], vec![2; 50]].concat();
+    let test: ChiSquareTest = chi_square_test(&dist, &samples);
+    assert_eq!(test.degrees_of_freedom, 1);
+    assert!(test.statistic.abs() < 1e-10);
+    assert!((test.p_value - 1.).abs() < 1e-10);
+
+    // samples outside the support cannot stem from the distribution
+    let outside: ChiSquareTest = chi_square_test(&dist, &[0, 1, 2, 7]);
+    assert!(outside.statistic.is_infinite());
+    assert_eq!(outside.p_value, 0.);
+
+    assert!(try_chi_square_test(&dist, &[]).is_err());
+    // too few samples to fill two bins
+    assert!(try_chi_square_test(&dist, &[1, 2]).is_err());
+}
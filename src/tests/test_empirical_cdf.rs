@@ -0,0 +1,69 @@
+#[cfg(test)]
+use crate::probability::induction::continuous_testing::{
+    ks_estimate_continuous_cdf, ks_evaluate_estimated_cdf,
+};
+#[cfg(test)]
+use crate::probability::induction::empirical_cdf::EmpiricalCdf;
+
+#[test]
+fn test_empirical_cdf_evaluate() {
+    let cdf: EmpiricalCdf = EmpiricalCdf::new(&[3., 1., 2., 2.]);
+    assert_eq!(cdf.len(), 4);
+    assert_eq!(cdf.sorted_samples(), &[1., 2., 2., 3.]);
+    assert_eq!(cdf.evaluate(0.), 0.);
+    assert_eq!(cdf.evaluate(1.), 0.25);
+    assert_eq!(cdf.evaluate(1.5), 0.25);
+    // ties jump by their multiplicity
+    assert_eq!(cdf.evaluate(2.), 0.75);
+    assert_eq!(cdf.evaluate_left(2.), 0.25);
+    assert_eq!(cdf.evaluate(3.), 1.);
+    assert_eq!(cdf.evaluate(10.), 1.);
+}
+
+#[test]
+fn test_empirical_cdf_quantile() {
+    let cdf: EmpiricalCdf = EmpiricalCdf::new(&[3., 1., 2., 2.]);
+    assert_eq!(cdf.quantile(0.), 1.);
+    assert_eq!(cdf.quantile(0.25), 1.);
+    assert_eq!(cdf.quantile(0.3), 2.);
+    assert_eq!(cdf.quantile(0.75), 2.);
+    assert_eq!(cdf.quantile(0.8), 3.);
+    assert_eq!(cdf.quantile(1.), 3.);
+
+    // 0.3 * 10 rounds to 3.0000000000000004
+    let samples: Vec<f64> = (1..=10).map(|i| i as f64).collect();
+    let cdf: EmpiricalCdf = EmpiricalCdf::new(&samples);
+    assert_eq!(cdf.quantile(0.3), 3.);
+    assert_eq!(cdf.quantile(0.7), 7.);
+    for &x in samples.iter() {
+        assert_eq!(cdf.quantile(cdf.evaluate(x)), x);
+    }
+}
+
+#[test]
+fn test_empirical_cdf_steps() {
+    let cdf: EmpiricalCdf = EmpiricalCdf::new(&[3., 1., 2., 2.]);
+    assert_eq!(cdf.steps(), vec![(1., 0.25), (2., 0.75), (3., 1.)]);
+    assert_eq!(
+        cdf.plot_data(),
+        vec![
+            (1., 0.),
+            (1., 0.25),
+            (2., 0.25),
+            (2., 0.75),
+            (3., 0.75),
+            (3., 1.)
+        ]
+    );
+
+    let est_dist: Vec<(f64, f64)> = ks_estimate_continuous_cdf(&vec![3., 1., 2., 2.]);
+    assert_eq!(est_dist, cdf.steps());
+    assert_eq!(ks_evaluate_estimated_cdf(&est_dist, &0.), 0.);
+    assert_eq!(ks_evaluate_estimated_cdf(&est_dist, &2.5), 0.75);
+}
+
+#[test]
+fn test_empirical_cdf_try_new() {
+    assert!(EmpiricalCdf::try_new(&[]).is_err());
+    assert!(EmpiricalCdf::try_new(&[1., f64::NAN]).is_err());
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, ExponentialDistribution, NormalDistribution,
+    PowerLawDistribution,
+};
+#[cfg(test)]
+use crate::probability::induction::continuous_testing::ks_distance;
+#[cfg(test)]
+use crate::probability::induction::decision_entropy::{
+    compute_decision_entropy, compute_metric_decision_entropy,
+};
+#[cfg(test)]
+use crate::probability::induction::goodness_of_fit::{
+    anderson_darling_p_value, anderson_darling_test, cramer_von_mises_p_value,
+    cramer_von_mises_test, GoodnessOfFitMetric, GoodnessOfFitTest,
+};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_goodness_of_fit_p_values() {
+    // tabulated asymptotic critical values at the 10%, 5% and 1% levels
+    for (statistic, level) in [(1.933, 0.1), (2.492, 0.05), (3.857, 0.01)] {
+        assert!((anderson_darling_p_value(statistic, 100_000) - level).abs() < 1e-3);
+    }
+    for (statistic, level) in [(0.34730, 0.1), (0.46136, 0.05), (0.74346, 0.01)] {
+        assert!((cramer_von_mises_p_value(statistic, 100_000) - level).abs() < 1e-3);
+    }
+    // exact upper tail probabilities for five samples, simulated with 4e6 draws
+    for (statistic, level) in [(0.5, 0.7382), (0.75, 0.5106)] {
+        assert!((anderson_darling_p_value(statistic, 5) - level).abs() < 1e-3);
+    }
+    assert_eq!(anderson_darling_p_value(0., 10), 1.);
+    assert_eq!(cramer_von_mises_p_value(0., 10), 1.);
+}
+
+#[test]
+fn test_goodness_of_fit_statistics() {
+    // samples placed at the midpoint quantiles give the minimal Cramér-von Mises statistic
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let samples: Vec<f64> = (0..10)
+        .map(|i| normal.quantile((i as f64 + 0.5) / 10.))
+        .collect();
+    let cramer_von_mises: GoodnessOfFitTest = cramer_von_mises_test(&normal, &samples);
+    assert!((cramer_von_mises.statistic - 1. / 120.).abs() < 1e-12);
+
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let samples: Vec<f64> = continuous_sample_with(200, &normal, &mut rng);
+    assert!(anderson_darling_test(&normal, &samples).p_value > 0.01);
+    assert!(cramer_von_mises_test(&normal, &samples).p_value > 0.01);
+    let wide: NormalDistribution = NormalDistribution::new(0., 4.);
+    assert!(anderson_darling_test(&wide, &samples).p_value < 1e-3);
+    assert!(cramer_von_mises_test(&wide, &samples).p_value < 1e-3);
+}
+
+#[test]
+fn test_metric_decision_entropy() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2.5, 1.);
+    let samples: Vec<f64> = continuous_sample_with(100, &power_law, &mut rng);
+    assert_eq!(
+        GoodnessOfFitMetric::KolmogorovSmirnov.estimation_error(&power_law, &samples),
+        ks_distance(&power_law, &samples)
+    );
+    let est_normal: NormalDistribution = NormalDistribution::estimate(&samples);
+    let est_power_law: PowerLawDistribution = PowerLawDistribution::estimate(&samples);
+    let metric: GoodnessOfFitMetric = GoodnessOfFitMetric::CramerVonMises;
+    let errors: Vec<f64> = vec![
+        metric.estimation_error(&est_normal, &samples),
+        metric.estimation_error(&est_power_law, &samples),
+    ];
+    assert!(errors[1] < errors[0]);
+    assert_eq!(
+        compute_metric_decision_entropy(metric, &[&est_normal, &est_power_law], &samples)
+            .to_float(),
+        compute_decision_entropy(&errors).to_float()
+    );
+}
+
+#[test]
+fn test_anderson_darling_fitted_boundary() {
+    // fitted power law and exponential place x_min at the sample minimum, where F = 0
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2.5, 1.);
+    let samples: Vec<f64> = continuous_sample_with(200, &power_law, &mut rng);
+    let est_normal: NormalDistribution = NormalDistribution::estimate(&samples);
+    let est_power_law: PowerLawDistribution = PowerLawDistribution::estimate(&samples);
+    let est_exponential: ExponentialDistribution = ExponentialDistribution::estimate(&samples);
+    let metric: GoodnessOfFitMetric = GoodnessOfFitMetric::AndersonDarling;
+    let errors: Vec<f64> = vec![
+        metric.estimation_error(&est_normal, &samples),
+        metric.estimation_error(&est_power_law, &samples),
+        metric.estimation_error(&est_exponential, &samples),
+    ];
+    assert!(errors.iter().all(|error| error.is_finite()));
+    assert!(errors[1] < errors[0] && errors[1] < errors[2]);
+    assert!(anderson_darling_test(&est_power_law, &samples).p_value > 0.);
+}
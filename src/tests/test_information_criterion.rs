@@ -0,0 +1,88 @@
+#[cfg(test)]
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, ExponentialDistribution, NormalDistribution,
+    PowerLawDistribution,
+};
+#[cfg(test)]
+use crate::probability::induction::information_criterion::{
+    aic, aicc, bic, information_criterion_weights, InformationCriterion,
+};
+#[cfg(test)]
+use crate::probability::induction::model_selection::{
+    CandidateModel, ModelSelection, ModelSelectionResult,
+};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_information_criteria() {
+    assert_eq!(aic(-10., 2), 24.);
+    assert_eq!(aicc(-10., 2, 10), 24. + 12. / 7.);
+    assert!(aicc(-10., 2, 3).is_infinite());
+    assert_eq!(bic(-10., 2, 10), 2. * 10f64.ln() + 20.);
+
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let samples: Vec<f64> = vec![-1., 0., 1.];
+    assert_eq!(
+        InformationCriterion::Bic.evaluate(&normal, 2, &samples),
+        bic(normal.log_likelihood(&samples), 2, 3)
+    );
+}
+
+#[test]
+fn test_information_criterion_weights() {
+    let weights: Vec<f64> = information_criterion_weights(&[10., 12., 20.]);
+    let sum: f64 = 1. + (-1f64).exp() + (-5f64).exp();
+    assert!((weights[0] - 1. / sum).abs() < 1e-15);
+    assert!((weights[1] - (-1f64).exp() / sum).abs() < 1e-15);
+    assert!((weights[2] - (-5f64).exp() / sum).abs() < 1e-15);
+    assert_eq!(
+        information_criterion_weights(&[f64::INFINITY; 2]),
+        vec![0.5; 2]
+    );
+}
+
+#[test]
+fn test_information_criterion_model_selection() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(1., 0.);
+    let samples: Vec<f64> = continuous_sample_with(200, &exponential, &mut rng);
+    let selection: ModelSelection = ModelSelection::new()
+        .with_information_criterion(InformationCriterion::Aic)
+        .with_candidate(CandidateModel::normal())
+        .with_candidate(CandidateModel::exponential());
+    let result: ModelSelectionResult = selection.select(&samples);
+    assert_eq!(result.winning_fit().name, "exponential");
+    for fit in result.fits.iter() {
+        assert_eq!(fit.score, aic(fit.log_likelihood, fit.num_parameters));
+    }
+    let scores: Vec<f64> = result.fits.iter().map(|fit| fit.score).collect();
+    assert_eq!(
+        result.decision_probabilities(),
+        information_criterion_weights(&scores)
+    );
+}
+
+#[test]
+fn test_power_law_candidate_maximises_likelihood() {
+    let mut rng: StdRng = StdRng::seed_from_u64(3);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2.5, 2.);
+    let samples: Vec<f64> = continuous_sample_with(500, &power_law, &mut rng);
+    let x_min: f64 = samples.iter().copied().fold(f64::INFINITY, f64::min);
+    let fit: Box<dyn ContinuousProbabilityDistribution> =
+        CandidateModel::power_law().fit(&samples).unwrap();
+    assert_eq!(fit.domain().0, x_min);
+    // the log-likelihood counted by the information criteria is a maximum over both parameters
+    let log_likelihood: f64 = fit.log_likelihood(&samples);
+    let exponent: f64 =
+        1. + samples.len() as f64 / samples.iter().map(|x| (x / x_min).ln()).sum::<f64>();
+    for perturbation in [-0.05, 0.05] {
+        let perturbed: PowerLawDistribution =
+            PowerLawDistribution::new(0., exponent + perturbation, x_min);
+        assert!(perturbed.log_likelihood(&samples) < log_likelihood);
+    }
+    let lower: PowerLawDistribution = PowerLawDistribution::new(0., exponent, 0.99 * x_min);
+    assert!(lower.log_likelihood(&samples) < log_likelihood);
+}
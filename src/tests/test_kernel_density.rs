@@ -0,0 +1,146 @@
+#[cfg(test)]
+use crate::error::KoError;
+#[cfg(test)]
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, NormalDistribution,
+};
+#[cfg(test)]
+use crate::probability::induction::continuous_testing::ks_distance;
+#[cfg(test)]
+use crate::probability::kernel_density::{
+    scott_bandwidth, silverman_bandwidth, BandwidthSelection, Kernel, KernelDensityEstimate,
+};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(test)]
+fn normal_samples(n: usize) -> Vec<f64> {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    continuous_sample_with(n, &NormalDistribution::new(0., 1.), &mut rng)
+}
+
+#[test]
+fn test_kernel_density_pdf_cdf() {
+    let samples: Vec<f64> = normal_samples(200);
+    for kernel in [Kernel::Gaussian, Kernel::Epanechnikov] {
+        let kde: KernelDensityEstimate =
+            KernelDensityEstimate::new(&samples, kernel, BandwidthSelection::Silverman);
+        // the cdf is the integral of the pdf
+        let (a, b): (f64, f64) = (-1., 0.5);
+        let num_steps: usize = 10000;
+        let step: f64 = (b - a) / num_steps as f64;
+        let integral: f64 = (0..num_steps)
+            .map(|i| kde.pdf(a + (i as f64 + 0.5) * step) * step)
+            .sum();
+        assert!((integral - kde.measure(&(a, b))).abs() < 1e-6);
+        assert!(kde.cdf(-20.) < 1e-10);
+        assert!((kde.cdf(20.) - 1.).abs() < 1e-10);
+        assert!(kde.pdf(0.) <= kde.range().1);
+    }
+    // the epanechnikov estimate vanishes outside its domain
+    let kde: KernelDensityEstimate =
+        KernelDensityEstimate::new(&samples, Kernel::Epanechnikov, BandwidthSelection::Scott);
+    let (lower, upper): (f64, f64) = kde.domain();
+    assert_eq!(kde.pdf(lower - 1e-9), 0.);
+    assert!(kde.cdf(lower) < 1e-12);
+    assert!((kde.cdf(upper) - 1.).abs() < 1e-12);
+}
+
+#[test]
+fn test_kernel_density_bandwidth() {
+    let samples: Vec<f64> = vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10.];
+    let sigma: f64 = (55f64 / 6.).sqrt();
+    let scott: f64 = scott_bandwidth(&samples, Kernel::Gaussian);
+    assert!((scott - 1.06 * sigma * 10f64.powf(-0.2)).abs() < 1e-12);
+    // sigma is smaller than the interquartile range 7.75 - 3.25 over 1.34
+    let silverman: f64 = silverman_bandwidth(&samples, Kernel::Gaussian);
+    assert!((silverman - 0.9 * sigma * 10f64.powf(-0.2)).abs() < 1e-12);
+    // an outlier inflates sigma but not the interquartile range
+    let outlier: Vec<f64> = vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 100.];
+    let robust: f64 = silverman_bandwidth(&outlier, Kernel::Gaussian);
+    assert!((robust - 0.9 * 4.5 / 1.34 * 10f64.powf(-0.2)).abs() < 1e-12);
+    assert!(silverman_bandwidth(&samples, Kernel::Epanechnikov) > silverman);
+
+    let samples: Vec<f64> = normal_samples(300);
+    let lscv: KernelDensityEstimate = KernelDensityEstimate::new(
+        &samples,
+        Kernel::Gaussian,
+        BandwidthSelection::LeastSquaresCrossValidation,
+    );
+    let silverman: f64 = silverman_bandwidth(&samples, Kernel::Gaussian);
+    assert!(lscv.bandwidth() > silverman / 4. && lscv.bandwidth() < silverman * 3.);
+    let fixed: KernelDensityEstimate =
+        KernelDensityEstimate::new(&samples, Kernel::Gaussian, BandwidthSelection::Fixed(0.3));
+    assert_eq!(fixed.bandwidth(), 0.3);
+}
+
+#[test]
+fn test_kernel_density_fit_and_sample() {
+    let samples: Vec<f64> = normal_samples(500);
+    let kde: KernelDensityEstimate = KernelDensityEstimate::new(
+        &samples,
+        Kernel::Epanechnikov,
+        BandwidthSelection::Silverman,
+    );
+    assert!(ks_distance(&NormalDistribution::new(0., 1.), &samples) < 0.1);
+    assert!(ks_distance(&kde, &samples) < 0.05);
+
+    // smoothed bootstrap: the mean is the sample mean and the variance is inflated by h^2 / 5
+    let n: f64 = samples.len() as f64;
+    let sample_mean: f64 = samples.iter().sum::<f64>() / n;
+    let sample_variance: f64 = samples
+        .iter()
+        .map(|x| (x - sample_mean).powi(2))
+        .sum::<f64>()
+        / n;
+    assert!((kde.mean().unwrap() - sample_mean).abs() < 1e-12);
+    assert!(
+        (kde.variance().unwrap() - sample_variance - kde.bandwidth().powi(2) / 5.).abs() < 1e-10
+    );
+    let mut rng: StdRng = StdRng::seed_from_u64(1);
+    let draws: Vec<f64> = continuous_sample_with(20000, &kde, &mut rng);
+    let draw_mean: f64 = draws.iter().sum::<f64>() / draws.len() as f64;
+    let draw_variance: f64 =
+        draws.iter().map(|x| (x - draw_mean).powi(2)).sum::<f64>() / draws.len() as f64;
+    assert!((draw_mean - kde.mean().unwrap()).abs() < 0.05);
+    assert!((draw_variance - kde.variance().unwrap()).abs() < 0.05);
+}
+
+#[test]
+fn test_kernel_density_invalid() {
+    assert!(matches!(
+        KernelDensityEstimate::try_new(&[], Kernel::Gaussian, BandwidthSelection::Scott),
+        Err(KoError::EmptySamples)
+    ));
+    assert!(matches!(
+        KernelDensityEstimate::try_new(&[1.], Kernel::Gaussian, BandwidthSelection::Scott),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        KernelDensityEstimate::try_new(
+            &[1., f64::NAN],
+            Kernel::Gaussian,
+            BandwidthSelection::Scott
+        ),
+        Err(KoError::InvalidParameter(_))
+    ));
+    // identical samples have no spread to derive a bandwidth from
+    assert!(matches!(
+        KernelDensityEstimate::try_new(&[2., 2.], Kernel::Gaussian, BandwidthSelection::Silverman),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        KernelDensityEstimate::try_new(
+            &[2., 2., 2.],
+            Kernel::Epanechnikov,
+            BandwidthSelection::LeastSquaresCrossValidation
+        ),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        KernelDensityEstimate::try_new(&[1., 2.], Kernel::Gaussian, BandwidthSelection::Fixed(0.)),
+        Err(KoError::InvalidParameter(_))
+    ));
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+use crate::error::KoError;
+#[cfg(test)]
+use crate::probability::continuous_distribution::{
+    LogNormalDistribution, NormalDistribution, PowerLawDistribution,
+};
+#[cfg(test)]
+use crate::probability::induction::likelihood_ratio::{try_vuong_test, vuong_test, VuongTest};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_vuong_test() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new(0., 1.);
+    let samples: Vec<f64> = continuous_sample_with(500, &log_normal, &mut rng);
+    let est_log_normal: LogNormalDistribution = LogNormalDistribution::estimate(&samples);
+    let est_normal: NormalDistribution = NormalDistribution::estimate(&samples);
+
+    let test: VuongTest = vuong_test(&est_log_normal, &est_normal, &samples);
+    assert!(test.log_likelihood_ratio > 0.);
+    assert!(test.normalised_ratio > 0.);
+    assert!(test.p_value < 1e-3);
+
+    // swapping the candidates flips the sign but keeps the p-value
+    let swapped: VuongTest = vuong_test(&est_normal, &est_log_normal, &samples);
+    assert!((swapped.normalised_ratio + test.normalised_ratio).abs() < 1e-10);
+    assert!((swapped.p_value - test.p_value).abs() < 1e-10);
+
+    // identical candidates are indistinguishable
+    let identical: VuongTest = vuong_test(&est_normal, &est_normal, &samples);
+    assert_eq!(identical.log_likelihood_ratio, 0.);
+    assert_eq!(identical.p_value, 1.);
+}
+
+#[test]
+fn test_vuong_test_decisive() {
+    // a sample below x_min excludes the power law but not the log-normal
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2.5, 1.);
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new(0., 1.);
+    let test: VuongTest = vuong_test(&power_law, &log_normal, &[0.5, 2., 3.]);
+    assert_eq!(test.log_likelihood_ratio, f64::NEG_INFINITY);
+    assert_eq!(test.normalised_ratio, f64::NEG_INFINITY);
+    assert_eq!(test.p_value, 0.);
+    // a sample outside both supports leaves the ratio undefined
+    assert!(matches!(
+        try_vuong_test(&power_law, &log_normal, &[-1., 2.]),
+        Err(KoError::InvalidParameter(_))
+    ));
+
+    // the same nonzero ratio on every sample favours one distribution outright
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let shifted: NormalDistribution = NormalDistribution::new(1., 1.);
+    let test: VuongTest = vuong_test(&normal, &shifted, &[3., 3.]);
+    assert!(test.log_likelihood_ratio < 0.);
+    assert_eq!(test.normalised_ratio, f64::NEG_INFINITY);
+    assert_eq!(test.p_value, 0.);
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, NormalDistribution, PowerLawDistribution,
+};
+#[cfg(test)]
+use crate::probability::mixture_distribution::{GaussianMixtureFit, MixtureDistribution};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_mixture_distribution() {
+    // normal body with a power-law tail
+    let mixture: MixtureDistribution = MixtureDistribution::new(
+        vec![
+            Box::new(NormalDistribution::new(0., 1.)),
+            Box::new(PowerLawDistribution::new(0., 3.5, 1.)),
+        ],
+        vec![0.9, 0.1],
+    );
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 3.5, 1.);
+    for x in [-1., 0.5, 2., 10.] {
+        let pdf: f64 = 0.9 * normal.pdf(x) + 0.1 * power_law.pdf(x);
+        assert!((mixture.pdf(x) - pdf).abs() < 1e-15);
+        assert!((mixture.log_pdf(x) - pdf.ln()).abs() < 1e-13);
+        let cdf: f64 = 0.9 * normal.cdf(x) + 0.1 * power_law.cdf(x);
+        assert!((mixture.cdf(x) - cdf).abs() < 1e-15);
+    }
+    assert!((mixture.mean().unwrap() - 0.1 * power_law.mean().unwrap()).abs() < 1e-15);
+    // the tail dominates the third moment, which diverges for the power law
+    assert!(mixture.raw_moment(3).is_none());
+    assert!(MixtureDistribution::try_new(vec![], vec![]).is_err());
+    assert!(MixtureDistribution::try_new(
+        vec![Box::new(NormalDistribution::new(0., 1.))],
+        vec![0.5]
+    )
+    .is_err());
+}
+
+#[test]
+fn test_fit_gaussian_mixture() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let mixture: MixtureDistribution = MixtureDistribution::new(
+        vec![
+            Box::new(NormalDistribution::new(-3., 1.)),
+            Box::new(NormalDistribution::new(3., 0.25)),
+        ],
+        vec![0.3, 0.7],
+    );
+    let samples: Vec<f64> = continuous_sample_with(2000, &mixture, &mut rng);
+    let fit: GaussianMixtureFit = MixtureDistribution::fit_gaussian_mixture(&samples, 2, 1e-8, 500);
+
+    assert!(fit.converged);
+    assert!((fit.components[0].mean + 3.).abs() < 0.1);
+    assert!((fit.components[1].mean - 3.).abs() < 0.1);
+    assert!((fit.components[1].variance - 0.25).abs() < 0.05);
+    assert!((fit.weights[0] - 0.3).abs() < 0.05);
+    // expectation-maximisation never decreases the likelihood
+    assert!(fit
+        .log_likelihood_trace
+        .windows(2)
+        .all(|pair| pair[1] >= pair[0] - 1e-9));
+    assert!((fit.mixture().log_likelihood(&samples) - fit.log_likelihood()).abs() < 1e-6);
+
+    // stopping at the iteration limit still reports the likelihood of the returned mixture
+    let truncated: GaussianMixtureFit =
+        MixtureDistribution::fit_gaussian_mixture(&samples, 2, 1e-8, 1);
+    assert!(!truncated.converged);
+    assert_eq!(truncated.log_likelihood_trace.len(), 2);
+    assert!(
+        (truncated.mixture().log_likelihood(&samples) - truncated.log_likelihood()).abs() < 1e-6
+    );
+
+    assert!(MixtureDistribution::try_fit_gaussian_mixture(&samples, 0, 1e-8, 10).is_err());
+    assert!(MixtureDistribution::try_fit_gaussian_mixture(&[1., 1.], 1, 1e-8, 10).is_err());
+    assert!(MixtureDistribution::try_fit_gaussian_mixture(&samples, 2, 1e-8, 0).is_err());
+    assert!(
+        MixtureDistribution::try_fit_gaussian_mixture(&[0., f64::NAN, 1.], 2, 1e-8, 10).is_err()
+    );
+    assert!(
+        MixtureDistribution::try_fit_gaussian_mixture(&[0., f64::INFINITY, 1.], 2, 1e-8, 10)
+            .is_err()
+    );
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, ExponentialDistribution, NormalDistribution,
+};
+#[cfg(test)]
+use crate::probability::induction::information_criterion::InformationCriterion;
+#[cfg(test)]
+use crate::probability::induction::model_averaging::ModelAverage;
+#[cfg(test)]
+use crate::probability::induction::model_selection::{
+    CandidateModel, ModelSelection, ModelSelectionResult,
+};
+#[cfg(test)]
+use crate::probability::information::discrete_entropy;
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(test)]
+fn normal_exponential_average() -> ModelAverage {
+    ModelAverage::new(
+        vec!["normal".to_string(), "exponential".to_string()],
+        vec![
+            Box::new(NormalDistribution::new(0., 1.)),
+            Box::new(ExponentialDistribution::new(1., 0.)),
+        ],
+        vec![0.25, 0.75],
+    )
+}
+
+#[test]
+fn test_model_average() {
+    let average: ModelAverage = normal_exponential_average();
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(1., 0.);
+    for x in [-1., 0.5, 2.] {
+        let pdf: f64 = 0.25 * normal.pdf(x) + 0.75 * exponential.pdf(x);
+        assert!((average.pdf(x) - pdf).abs() < 1e-15);
+        assert!((average.log_pdf(x) - pdf.ln()).abs() < 1e-14);
+        let cdf: f64 = 0.25 * normal.cdf(x) + 0.75 * exponential.cdf(x);
+        assert!((average.cdf(x) - cdf).abs() < 1e-15);
+        assert!((average.cdf(average.quantile(cdf)) - cdf).abs() < 1e-10);
+    }
+    assert_eq!(average.mean(), Some(0.75));
+    assert_eq!(average.raw_moment(2), Some(0.25 + 0.75 * 2.));
+    assert_eq!(average.names()[1], "exponential");
+    assert_eq!(
+        average.decision_entropy().to_float(),
+        discrete_entropy(average.weights()).to_float()
+    );
+
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let samples: Vec<f64> = continuous_sample_with(20_000, &average, &mut rng);
+    let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+    assert!((mean - 0.75).abs() < 0.05);
+}
+
+#[test]
+fn test_model_average_from_selection() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(1., 0.);
+    let samples: Vec<f64> = continuous_sample_with(30, &exponential, &mut rng);
+    let result: ModelSelectionResult = ModelSelection::new()
+        .with_information_criterion(InformationCriterion::Bic)
+        .with_candidate(CandidateModel::normal())
+        .with_candidate(CandidateModel::exponential())
+        .select(&samples);
+    let probabilities: Vec<f64> = result.decision_probabilities();
+    let entropy: f64 = result.decision_entropy.to_float();
+    let average: ModelAverage = ModelAverage::from_selection(result);
+    assert_eq!(average.weights().probabilities, probabilities);
+    assert_eq!(average.decision_entropy().to_float(), entropy);
+    assert_eq!(average.models().len(), 2);
+
+    assert!(ModelAverage::try_new(vec![], vec![], vec![]).is_err());
+}
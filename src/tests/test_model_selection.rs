@@ -0,0 +1,113 @@
+#[cfg(test)]
+use crate::error::KoError;
+#[cfg(test)]
+use crate::probability::continuous_distribution::{LogNormalDistribution, NormalDistribution};
+#[cfg(test)]
+use crate::probability::induction::continuous_testing::ks_distance;
+#[cfg(test)]
+use crate::probability::induction::decision_entropy::{
+    compute_decision_entropy, DecisionScoring, ScoreMap,
+};
+#[cfg(test)]
+use crate::probability::induction::information_criterion::InformationCriterion;
+#[cfg(test)]
+use crate::probability::induction::model_selection::{
+    CandidateModel, ModelSelection, ModelSelectionResult,
+};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_model_selection() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new(0., 1.);
+    let samples: Vec<f64> = continuous_sample_with(300, &log_normal, &mut rng);
+    let selection: ModelSelection = ModelSelection::new()
+        .with_candidate(CandidateModel::normal())
+        .with_candidate(CandidateModel::exponential())
+        .with_candidate(CandidateModel::log_normal());
+    let result: ModelSelectionResult = selection.select(&samples);
+
+    assert_eq!(result.fits.len(), 3);
+    assert_eq!(result.winning_fit().name, "log-normal");
+    let est_normal: NormalDistribution = NormalDistribution::estimate(&samples);
+    assert_eq!(result.fits[0].score, ks_distance(&est_normal, &samples));
+    let probabilities: Vec<f64> = result.decision_probabilities();
+    assert!((probabilities.iter().sum::<f64>() - 1.).abs() < 1e-10);
+    let scores: Vec<f64> = result.fits.iter().map(|fit| fit.score).collect();
+    assert_eq!(
+        result.decision_entropy.to_float(),
+        compute_decision_entropy(&scores).to_float()
+    );
+}
+
+#[test]
+fn test_model_selection_failed_candidate() {
+    // the log-normal cannot be fitted to non-positive samples
+    let samples: Vec<f64> = vec![-1., 0.5, 2., 3., 4.5];
+    let selection: ModelSelection = ModelSelection::new()
+        .with_candidate(CandidateModel::log_normal())
+        .with_candidate(CandidateModel::normal());
+    let result: ModelSelectionResult = selection.select(&samples);
+    assert_eq!(result.fits.len(), 1);
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].name, "log-normal");
+    assert!(matches!(
+        result.failures[0].error,
+        KoError::InvalidParameter(_)
+    ));
+    assert_eq!(result.winning_fit().name, "normal");
+    assert_eq!(result.decision_probabilities(), vec![1.]);
+
+    let selection: ModelSelection =
+        ModelSelection::new().with_candidate(CandidateModel::log_normal());
+    assert!(matches!(
+        selection.try_select(&samples),
+        Err(KoError::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn test_model_selection_custom_candidate() {
+    let mut selection: ModelSelection = ModelSelection::new();
+    assert!(selection.try_select(&[1., 2.]).is_err());
+    // a fixed standard normal needs no fitting
+    selection.register(CandidateModel::new("standard normal", 0, |_: &[f64]| {
+        Ok::<NormalDistribution, KoError>(NormalDistribution::new(0., 1.))
+    }));
+    assert_eq!(selection.candidates()[0].name(), "standard normal");
+    let result: ModelSelectionResult = selection.select(&[0.]);
+    assert_eq!(result.winner, 0);
+    assert_eq!(result.fits[0].score, 0.5);
+    assert_eq!(result.fits[0].distribution.cdf(0.), 0.5);
+    assert!(selection.try_select(&[]).is_err());
+}
+
+#[test]
+fn test_model_selection_scoring() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let log_normal: LogNormalDistribution = LogNormalDistribution::new(0., 1.);
+    let samples: Vec<f64> = continuous_sample_with(300, &log_normal, &mut rng);
+    // the likelihood map scores log-likelihoods rather than goodness-of-fit statistics
+    let scoring: DecisionScoring = DecisionScoring::new(ScoreMap::Likelihood, 1.);
+    let selection: ModelSelection = ModelSelection::new()
+        .with_candidate(CandidateModel::normal())
+        .with_candidate(CandidateModel::log_normal())
+        .with_scoring(scoring);
+    let result: ModelSelectionResult = selection.select(&samples);
+    assert_eq!(result.winning_fit().name, "log-normal");
+    let log_likelihoods: Vec<f64> = result.fits.iter().map(|fit| fit.log_likelihood).collect();
+    assert_eq!(
+        result.decision_probabilities(),
+        scoring.decision_probabilities_from_log_likelihoods(&log_likelihoods)
+    );
+
+    // information criteria bring their own weights
+    let selection: ModelSelection = selection.with_information_criterion(InformationCriterion::Bic);
+    assert!(matches!(
+        selection.try_select(&samples),
+        Err(KoError::InvalidParameter(_))
+    ));
+}
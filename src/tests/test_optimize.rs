@@ -0,0 +1,11 @@
+#[cfg(test)]
+use crate::probability::utils::optimize::nelder_mead;
+
+#[test]
+fn test_nelder_mead() {
+    let tolerance: f64 = 1e-4;
+    let rosenbrock = |x: &[f64]| (1. - x[0]).powi(2) + 100. * (x[1] - x[0].powi(2)).powi(2);
+    let minimum: Vec<f64> = nelder_mead(rosenbrock, &[-1., 2.], 0.5, 1e-14, 10000);
+    assert!((minimum[0] - 1.).abs() < tolerance);
+    assert!((minimum[1] - 1.).abs() < tolerance);
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+use crate::error::KoError;
+#[cfg(test)]
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, NormalDistribution, PowerLawDistribution,
+};
+#[cfg(test)]
+use crate::probability::induction::power_law_fitting::{
+    fit_power_law_above, fit_power_law_tail, power_law_bootstrap, try_fit_power_law_above,
+    PowerLawBootstrap, PowerLawFit,
+};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[test]
+fn test_fit_power_law_tail() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    // uniform body below 5 with a power law tail above it
+    let tail_dist: PowerLawDistribution = PowerLawDistribution::new(0., 2.5, 5.);
+    let mut samples: Vec<f64> = (0..200).map(|_| rng.gen_range(1.0..5.0)).collect();
+    samples.extend(continuous_sample_with(300, &tail_dist, &mut rng));
+
+    let fit: PowerLawFit = fit_power_law_tail(&samples);
+    assert!((fit.x_min - 5.).abs() < 1.);
+    assert!((fit.distribution.exponent() - 2.5).abs() < 0.3);
+    assert_eq!(fit.distribution.domain().0, fit.x_min);
+    assert_eq!(
+        fit.num_tail_samples,
+        samples.iter().filter(|&&x| x >= fit.x_min).count()
+    );
+    assert!(fit.ks_statistic < 0.1);
+    assert_eq!(
+        fit_power_law_above(&samples, fit.x_min).exponent(),
+        fit.distribution.exponent()
+    );
+}
+
+#[test]
+fn test_fit_power_law_above() {
+    // the logarithms of the three samples at or above 1 sum to 3
+    let samples: Vec<f64> = vec![0.5, 1., std::f64::consts::E, std::f64::consts::E.powi(2)];
+    let fit: PowerLawDistribution = fit_power_law_above(&samples, 1.);
+    assert!((fit.exponent() - 2.).abs() < 1e-12);
+    assert_eq!(fit.min_x(), 1.);
+    assert!(matches!(
+        try_fit_power_law_above(&samples, 0.),
+        Err(KoError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        try_fit_power_law_above(&samples, 10.),
+        Err(KoError::EmptySamples)
+    ));
+    assert!(matches!(
+        try_fit_power_law_above(&[1., f64::NAN], 1.),
+        Err(KoError::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn test_power_law_bootstrap() {
+    let mut rng: StdRng = StdRng::seed_from_u64(1);
+    let power_law: PowerLawDistribution = PowerLawDistribution::new(0., 2.5, 1.);
+    let samples: Vec<f64> = continuous_sample_with(100, &power_law, &mut rng);
+    let bootstrap: PowerLawBootstrap = power_law_bootstrap(&samples, 10, 2);
+    assert_eq!(bootstrap.num_replicates, 10);
+    assert!(bootstrap.p_value > 0.1);
+    // identical seeds reproduce the p-value
+    assert_eq!(
+        bootstrap.p_value,
+        power_law_bootstrap(&samples, 10, 2).p_value
+    );
+
+    // normal samples have no power law tail
+    let normal: NormalDistribution = NormalDistribution::new(10., 1.);
+    let samples: Vec<f64> = continuous_sample_with(100, &normal, &mut rng);
+    let bootstrap: PowerLawBootstrap = power_law_bootstrap(&samples, 10, 2);
+    assert!(bootstrap.p_value < 0.1);
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+use crate::probability::continuous_distribution::ExponentialDistribution;
+#[cfg(test)]
+use crate::probability::induction::information_criterion::InformationCriterion;
+#[cfg(test)]
+use crate::probability::induction::model_selection::{CandidateModel, ModelSelection};
+#[cfg(test)]
+use crate::probability::induction::sequential_selection::{
+    SequentialSelection, SequentialSelectionResult,
+};
+#[cfg(test)]
+use crate::probability::utils::sample::continuous_sample_with;
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(test)]
+fn selection() -> ModelSelection {
+    ModelSelection::new()
+        .with_information_criterion(InformationCriterion::Aic)
+        .with_candidate(CandidateModel::normal())
+        .with_candidate(CandidateModel::exponential())
+}
+
+#[test]
+fn test_sequential_selection_stops() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(1., 0.);
+    let samples: Vec<f64> = continuous_sample_with(1000, &exponential, &mut rng);
+    let sequential: SequentialSelection = SequentialSelection::new(selection(), 0.1, 5, 1000);
+    let result: SequentialSelectionResult = sequential.run(samples);
+
+    assert!(result.converged);
+    assert_eq!(result.chosen_model(), "exponential");
+    assert!(result.stopping_time < 1000);
+    assert_eq!(result.entropy_trajectory.len(), result.stopping_time - 9);
+    assert_eq!(
+        result.entropy_trajectory.last().unwrap().0,
+        result.stopping_time as f64
+    );
+    // the last `patience` entropies are all below the threshold
+    let trajectory_len: usize = result.entropy_trajectory.len();
+    assert!(result.entropy_trajectory[trajectory_len - 5..]
+        .iter()
+        .all(|&(_, entropy)| entropy < 0.1));
+}
+
+#[test]
+fn test_sequential_selection_budget() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let exponential: ExponentialDistribution = ExponentialDistribution::new(1., 0.);
+    let samples: Vec<f64> = continuous_sample_with(1000, &exponential, &mut rng);
+    // a vanishing threshold can never be met
+    let sequential: SequentialSelection =
+        SequentialSelection::new(selection(), 0., 1, 50).with_min_samples(20);
+    let result: SequentialSelectionResult = sequential.run(samples.clone());
+    assert!(!result.converged);
+    assert_eq!(result.stopping_time, 50);
+    assert_eq!(result.entropy_trajectory.len(), 31);
+
+    assert!(sequential.try_run(samples[..10].to_vec()).is_err());
+    assert!(SequentialSelection::try_new(selection(), 0.1, 0, 50).is_err());
+    assert!(SequentialSelection::try_new(selection(), 0.1, 1, 5).is_err());
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+use crate::probability::continuous_distribution::{
+    ContinuousProbabilityDistribution, NormalDistribution, PowerLawDistribution,
+};
+#[cfg(test)]
+use crate::probability::induction::sequential_testing::{
+    SequentialProbabilityRatioTest, SprtDecision, SprtExpectedSampleSize,
+};
+#[cfg(test)]
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_sprt_expected_sample_size() {
+    let sprt: SequentialProbabilityRatioTest<NormalDistribution, NormalDistribution> =
+        SequentialProbabilityRatioTest::new(
+            NormalDistribution::new(0., 1.),
+            NormalDistribution::new(1., 1.),
+            0.05,
+            0.05,
+        );
+    assert!((sprt.upper_threshold() - 19f64.ln()).abs() < 1e-15);
+    assert!((sprt.lower_threshold() + 19f64.ln()).abs() < 1e-15);
+    // both drifts equal the Kullback-Leibler divergence 1/2
+    let expected: SprtExpectedSampleSize = sprt.expected_sample_size().unwrap();
+    let wald: f64 = 0.9 * 19f64.ln() / 0.5;
+    assert!((expected.under_h0 - wald).abs() < 1e-3);
+    assert!((expected.under_h1 - wald).abs() < 1e-3);
+
+    // the first normal sample below the power law's x_min ends the test under H0
+    let normal: NormalDistribution = NormalDistribution::new(0., 1.);
+    let sprt: SequentialProbabilityRatioTest<NormalDistribution, PowerLawDistribution> =
+        SequentialProbabilityRatioTest::new(
+            normal.clone(),
+            PowerLawDistribution::new(0., 2., 1.),
+            0.05,
+            0.05,
+        );
+    let expected: SprtExpectedSampleSize = sprt.expected_sample_size().unwrap();
+    assert!((expected.under_h0 - 1. / normal.cdf(1.)).abs() < 1e-3);
+    assert!(expected.under_h1.is_finite() && expected.under_h1 >= 1.);
+    // identical hypotheses have no drift
+    let sprt: SequentialProbabilityRatioTest<NormalDistribution, NormalDistribution> =
+        SequentialProbabilityRatioTest::new(
+            NormalDistribution::new(0., 1.),
+            NormalDistribution::new(0., 1.),
+            0.05,
+            0.05,
+        );
+    assert!(sprt.expected_sample_size().is_none());
+}
+
+#[test]
+fn test_sprt_error_rates() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let h0: NormalDistribution = NormalDistribution::new(0., 1.);
+    let h1: NormalDistribution = NormalDistribution::new(1., 1.);
+    let mut sprt: SequentialProbabilityRatioTest<NormalDistribution, NormalDistribution> =
+        SequentialProbabilityRatioTest::new(h0.clone(), h1.clone(), 0.05, 0.05);
+    let num_trials: usize = 500;
+    let mut false_alarms: usize = 0;
+    let mut misses: usize = 0;
+    for _ in 0..num_trials {
+        sprt.reset();
+        let decision: SprtDecision = sprt.run((0..).map(|_| h0.sample_with(&mut rng)));
+        if decision == SprtDecision::AcceptH1 {
+            false_alarms += 1;
+        }
+        sprt.reset();
+        let decision: SprtDecision = sprt.run((0..).map(|_| h1.sample_with(&mut rng)));
+        if decision == SprtDecision::AcceptH0 {
+            misses += 1;
+        }
+    }
+    assert!((false_alarms as f64 / num_trials as f64) < 0.08);
+    assert!((misses as f64 / num_trials as f64) < 0.08);
+}
+
+#[test]
+fn test_sprt_update() {
+    let mut sprt: SequentialProbabilityRatioTest<NormalDistribution, PowerLawDistribution> =
+        SequentialProbabilityRatioTest::new(
+            NormalDistribution::new(0., 1.),
+            PowerLawDistribution::new(0., 2., 1.),
+            0.01,
+            0.01,
+        );
+    // a negative sample is impossible under the power law
+    assert_eq!(sprt.update(-0.5), SprtDecision::AcceptH0);
+    assert_eq!(sprt.update(100.), SprtDecision::AcceptH0);
+    assert_eq!(sprt.num_samples(), 1);
+
+    sprt.reset();
+    // a NaN sample carries no evidence and is not counted
+    assert_eq!(sprt.update(f64::NAN), SprtDecision::Continue);
+    assert_eq!(sprt.num_samples(), 0);
+    assert_eq!(sprt.update(2.), SprtDecision::Continue);
+    assert_eq!(sprt.run(vec![50.]), SprtDecision::AcceptH1);
+    assert_eq!(sprt.num_samples(), 2);
+    assert!(sprt.log_likelihood_ratio() > sprt.upper_threshold());
+
+    assert!(SequentialProbabilityRatioTest::try_new(
+        NormalDistribution::new(0., 1.),
+        NormalDistribution::new(1., 1.),
+        0.6,
+        0.5
+    )
+    .is_err());
+}
@@ -1,5 +1,5 @@
 #[cfg(test)]
-use crate::probability::utils::softmax::softmax;
+use crate::probability::utils::softmax::{softmax, softmax_with_temperature};
 
 #[test]
 fn test_softmax() {
@@ -18,3 +18,19 @@ fn test_softmax() {
         assert!((a - b).abs() < tolerance);
     }
 }
+
+#[test]
+fn test_softmax_with_temperature() {
+    let tolerance: f64 = 1e-15;
+    let x: Vec<f64> = vec![0., 2f64.ln()];
+    let y: Vec<f64> = softmax_with_temperature(&x, 1.);
+    assert!((y[0] - 1. / 3.).abs() < tolerance);
+    assert!((y[1] - 2. / 3.).abs() < tolerance);
+    // halving the temperature squares the odds
+    let y: Vec<f64> = softmax_with_temperature(&x, 2.);
+    assert!((y[0] - 1. / 5.).abs() < tolerance);
+
+    // large inputs do not overflow
+    let y: Vec<f64> = softmax_with_temperature(&[-1e4, -1e4 + 2f64.ln()], 1.);
+    assert!((y[1] - 2. / 3.).abs() < 1e-12);
+}